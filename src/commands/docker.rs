@@ -1,33 +1,214 @@
 //! Docker operations (using bollard library)
 
-use bollard::container::{ListContainersOptions, StatsOptions};
-use bollard::service::ContainerSummary;
+use crate::config::{DockerSettings, EndpointSettings};
+use crate::utils::build_context;
+use crate::utils::compose::{ComposeProject, PROJECT_LABEL, SERVICE_LABEL};
+use crate::utils::docker_host::{self, Address, Endpoint, Source};
+use crate::utils::fs_helpers::format_size;
+use crate::utils::time::relative_time;
+use bollard::container::{
+    KillContainerOptions, ListContainersOptions, RemoveContainerOptions, RestartContainerOptions,
+    StatsOptions, StopContainerOptions,
+};
+use bollard::container::{LogOutput, MemoryStatsStats, Stats};
+use bollard::errors::Error as DockerError;
+use bollard::exec::{CreateExecOptions, ResizeExecOptions, StartExecOptions, StartExecResults};
+use bollard::image::{BuildImageOptions, ListImagesOptions, TagImageOptions};
+use bollard::service::{
+    BuildCache, ContainerInspectResponse, ContainerState, ContainerStateStatusEnum,
+    ContainerSummary, EventMessage, HealthStatusEnum, ImageInspect, Network,
+    SystemDataUsageResponse, Volume,
+};
+use bollard::system::EventsOptions;
 use bollard::Docker;
 use colored::Colorize;
 use comfy_table::{presets::UTF8_FULL, Cell, Color, Table};
+use dialoguer::{theme::ColorfulTheme, Confirm};
 use futures::stream::StreamExt;
+use indicatif::{ProgressBar, ProgressStyle};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::{IsTerminal, Read, Write};
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::io::AsyncWriteExt;
 
-pub fn ps() {
-    // Create a Tokio runtime
+/// `--host` / `--context` given on the command line, applied to every connection
+static ENDPOINT_FLAGS: OnceLock<(Option<String>, Option<String>)> = OnceLock::new();
+
+/// Select the engine for this process; call before any docker command runs
+pub fn select_endpoint(host: Option<String>, context: Option<String>) {
+    let _ = ENDPOINT_FLAGS.set((host, context));
+}
+
+fn resolve_endpoint() -> anyhow::Result<Endpoint> {
+    let (host, context) = ENDPOINT_FLAGS.get().cloned().unwrap_or_default();
+
+    // A broken docker.toml shouldn't hide an explicit --host
+    let settings = match DockerSettings::load() {
+        Ok(settings) => settings.endpoint,
+        Err(e) if host.is_some() || context.is_some() => {
+            eprintln!("{} {:#}", "!".yellow(), e);
+            EndpointSettings::default()
+        }
+        Err(e) => return Err(e),
+    };
+
+    docker_host::resolve(host.as_deref(), context.as_deref(), &settings)
+}
+
+fn connect(endpoint: &Endpoint) -> Result<Docker, DockerError> {
+    const TIMEOUT: u64 = 120;
+
+    match (&endpoint.address, &endpoint.tls) {
+        (Address::Unix(path), _) => Docker::connect_with_socket(
+            &path.to_string_lossy(),
+            TIMEOUT,
+            bollard::API_DEFAULT_VERSION,
+        ),
+        (Address::NamedPipe(_), _) => Docker::connect_with_socket(
+            &endpoint.address.to_string(),
+            TIMEOUT,
+            bollard::API_DEFAULT_VERSION,
+        ),
+        (Address::Tcp(addr), Some(tls)) => Docker::connect_with_ssl(
+            addr,
+            &tls.key,
+            &tls.cert,
+            &tls.ca,
+            TIMEOUT,
+            bollard::API_DEFAULT_VERSION,
+        ),
+        (Address::Tcp(addr), None) => {
+            Docker::connect_with_http(addr, TIMEOUT, bollard::API_DEFAULT_VERSION)
+        }
+    }
+}
+
+/// A `docker` CLI command aimed at the same engine as our own client
+fn docker_cli(endpoint: &Endpoint) -> std::process::Command {
+    let mut command = std::process::Command::new("docker");
+    command
+        .env_remove("DOCKER_HOST")
+        .env_remove("DOCKER_CONTEXT")
+        .args(["-H", &endpoint.address.to_string()]);
+
+    match &endpoint.tls {
+        // TlsFiles come from a single directory holding ca/cert/key.pem
+        Some(tls) => {
+            let dir = tls.ca.parent().unwrap_or(std::path::Path::new("."));
+            command
+                .env("DOCKER_TLS_VERIFY", "1")
+                .env("DOCKER_CERT_PATH", dir);
+        }
+        None => {
+            command.env_remove("DOCKER_TLS_VERIFY");
+        }
+    }
+
+    command
+}
+
+/// Run `f` against the selected engine on a fresh runtime.
+/// Returns `None` (after reporting why) if the runtime or client couldn't be set up.
+fn with_docker<T, F, Fut>(f: F) -> Option<T>
+where
+    F: FnOnce(Docker) -> Fut,
+    Fut: std::future::Future<Output = T>,
+{
     let rt = match tokio::runtime::Runtime::new() {
         Ok(r) => r,
         Err(e) => {
             eprintln!("{} Failed to create runtime: {}", "✗".red(), e);
-            return;
+            return None;
         }
     };
 
-    rt.block_on(async {
-        // Connect to Docker
-        let docker = match Docker::connect_with_local_defaults() {
-            Ok(d) => d,
-            Err(e) => {
-                eprintln!("{} Failed to connect to Docker: {}", "✗".red(), e);
-                eprintln!("  Make sure Docker is running");
-                return;
-            }
-        };
+    let endpoint = match resolve_endpoint() {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+            eprintln!("{} Failed to select Docker endpoint: {:#}", "✗".red(), e);
+            return None;
+        }
+    };
+
+    // The TLS and socket clients must be built inside the runtime
+    let _guard = rt.enter();
+    let docker = match connect(&endpoint) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{} Failed to connect to Docker: {}", "✗".red(), e);
+            eprintln!("  Endpoint: {}", endpoint);
+            eprintln!("  Make sure Docker is running, or pick one with --host / --context");
+            return None;
+        }
+    };
+
+    Some(rt.block_on(f(docker)))
+}
+
+/// List Docker CLI contexts and show which endpoint commands will use
+pub fn contexts() -> bool {
+    let lookup = docker_host::Lookup::system();
+    let contexts = match docker_host::list_contexts(&lookup) {
+        Ok(contexts) => contexts,
+        Err(e) => {
+            eprintln!("{} Failed to read Docker contexts: {:#}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    let endpoint = match resolve_endpoint() {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+            eprintln!("{} Failed to select Docker endpoint: {:#}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    println!("\n{}", "Docker Contexts".cyan().bold());
+    println!("{}", "=".repeat(80));
+
+    if contexts.is_empty() {
+        println!("{}", "No contexts defined".dimmed());
+    } else {
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Name").fg(Color::Cyan),
+            Cell::new("Endpoint").fg(Color::Cyan),
+            Cell::new("TLS").fg(Color::Cyan),
+            Cell::new("Description").fg(Color::Cyan),
+        ]);
+
+        for context in &contexts {
+            let active = matches!(
+                &endpoint.source,
+                Source::ContextFlag(name) | Source::ContextEnv(name) | Source::CurrentContext(name)
+                    if *name == context.name
+            );
+            let name = if active {
+                Cell::new(format!("{} *", context.name)).fg(Color::Green)
+            } else {
+                Cell::new(&context.name)
+            };
+
+            table.add_row(vec![
+                name,
+                Cell::new(context.host.as_deref().unwrap_or("-")),
+                Cell::new(if context.tls_dir.is_some() { "yes" } else { "" }),
+                Cell::new(context.description.as_deref().unwrap_or("")),
+            ]);
+        }
+
+        println!("{}", table);
+    }
+
+    println!("\n{} {}", "Using:".bold(), endpoint);
+    true
+}
 
+pub fn ps() {
+    with_docker(|docker| async move {
         // List containers
         let options = Some(ListContainersOptions::<String> {
             all: true,
@@ -35,12 +216,18 @@ pub fn ps() {
         });
 
         match docker.list_containers(options).await {
-            Ok(containers) => {
+            Ok(mut containers) => {
                 if containers.is_empty() {
                     println!("{} No containers found", "!".yellow());
                     return;
                 }
 
+                // Group compose projects together, standalone containers last
+                containers.sort_by_key(|c| {
+                    let project = compose_labels(c).map(|(project, _)| project.to_string());
+                    (project.is_none(), project)
+                });
+
                 println!("\n{}", "Docker Containers".cyan().bold());
                 println!("{}", "=".repeat(80));
 
@@ -49,6 +236,7 @@ pub fn ps() {
                 table.set_header(vec![
                     Cell::new("ID").fg(Color::Cyan),
                     Cell::new("Name").fg(Color::Cyan),
+                    Cell::new("Project").fg(Color::Cyan),
                     Cell::new("Image").fg(Color::Cyan),
                     Cell::new("Status").fg(Color::Cyan),
                     Cell::new("Ports").fg(Color::Cyan),
@@ -71,6 +259,11 @@ pub fn ps() {
 
                     let ports = format_ports(&container);
 
+                    let project = match compose_labels(&container) {
+                        Some((project, service)) => format!("{}/{}", project, service),
+                        None => "-".to_string(),
+                    };
+
                     let status_cell = match state {
                         "running" => Cell::new(status_str).fg(Color::Green),
                         "exited" => Cell::new(status_str).fg(Color::Red),
@@ -80,6 +273,7 @@ pub fn ps() {
                     table.add_row(vec![
                         Cell::new(short_id),
                         Cell::new(name),
+                        Cell::new(project),
                         Cell::new(image),
                         status_cell,
                         Cell::new(&ports),
@@ -95,142 +289,673 @@ pub fn ps() {
     });
 }
 
-pub fn stats(container_name: &str) {
-    let rt = match tokio::runtime::Runtime::new() {
-        Ok(r) => r,
-        Err(e) => {
-            eprintln!("{} Failed to create runtime: {}", "✗".red(), e);
-            return;
+/// Columns `stats` can sort by
+pub const STATS_SORT_KEYS: &[&str] = &["cpu", "mem", "net", "block", "pids", "name"];
+
+/// One container's resource usage, derived from a stats sample
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+struct StatsRow {
+    id: String,
+    name: String,
+    cpu_percent: f64,
+    memory_usage: u64,
+    memory_limit: u64,
+    memory_percent: f64,
+    net_rx: u64,
+    net_tx: u64,
+    block_read: u64,
+    block_write: u64,
+    pids: u64,
+}
+
+impl StatsRow {
+    /// Compute usage the way `docker stats` does
+    fn from_stats(stats: &Stats) -> Self {
+        let cpu_delta = stats
+            .cpu_stats
+            .cpu_usage
+            .total_usage
+            .saturating_sub(stats.precpu_stats.cpu_usage.total_usage);
+        let system_delta = stats
+            .cpu_stats
+            .system_cpu_usage
+            .unwrap_or(0)
+            .saturating_sub(stats.precpu_stats.system_cpu_usage.unwrap_or(0));
+        let cpus = stats.cpu_stats.online_cpus.unwrap_or_else(|| {
+            stats
+                .cpu_stats
+                .cpu_usage
+                .percpu_usage
+                .as_ref()
+                .map(|p| p.len() as u64)
+                .unwrap_or(1)
+        });
+        let cpu_percent = if system_delta > 0 {
+            cpu_delta as f64 / system_delta as f64 * cpus as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        // Page cache can be reclaimed, so it isn't counted as used
+        let cache = match &stats.memory_stats.stats {
+            Some(MemoryStatsStats::V1(v1)) => v1.total_inactive_file,
+            Some(MemoryStatsStats::V2(v2)) => v2.inactive_file,
+            None => 0,
+        };
+        let memory_usage = stats.memory_stats.usage.unwrap_or(0).saturating_sub(cache);
+        let memory_limit = stats.memory_stats.limit.unwrap_or(0);
+        let memory_percent = if memory_limit > 0 {
+            memory_usage as f64 / memory_limit as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        let (net_rx, net_tx) = stats
+            .networks
+            .iter()
+            .flat_map(|networks| networks.values())
+            .fold((0, 0), |(rx, tx), n| (rx + n.rx_bytes, tx + n.tx_bytes));
+
+        let (block_read, block_write) = stats
+            .blkio_stats
+            .io_service_bytes_recursive
+            .iter()
+            .flatten()
+            .fold((0, 0), |(read, write), entry| {
+                match entry.op.to_lowercase().as_str() {
+                    "read" => (read + entry.value, write),
+                    "write" => (read, write + entry.value),
+                    _ => (read, write),
+                }
+            });
+
+        Self {
+            id: stats.id.clone(),
+            name: stats.name.trim_start_matches('/').to_string(),
+            cpu_percent,
+            memory_usage,
+            memory_limit,
+            memory_percent,
+            net_rx,
+            net_tx,
+            block_read,
+            block_write,
+            pids: stats.pids_stats.current.unwrap_or(0),
         }
-    };
+    }
+}
 
-    rt.block_on(async {
-        let docker = match Docker::connect_with_local_defaults() {
-            Ok(d) => d,
+/// Resource usage for the given containers, or every running one. Streams and
+/// refreshes in place unless `no_stream` is set; `json` output is always a snapshot.
+pub fn stats(containers: &[String], no_stream: bool, format: &str, sort: &str) -> bool {
+    if !STATS_SORT_KEYS.contains(&sort) {
+        eprintln!("{} Unknown sort key: {}", "✗".red(), sort);
+        eprintln!("Sort by: {}", STATS_SORT_KEYS.join(", "));
+        return false;
+    }
+
+    if !matches!(format, "table" | "json") {
+        eprintln!("{} Unknown format: {}", "✗".red(), format);
+        return false;
+    }
+
+    with_docker(|docker| async move {
+        if no_stream || format == "json" {
+            stats_snapshot(&docker, containers, format, sort).await
+        } else {
+            stats_live(&docker, containers, sort).await
+        }
+    })
+    .unwrap_or(false)
+}
+
+async fn running_containers(docker: &Docker) -> Result<Vec<String>, DockerError> {
+    let containers = docker
+        .list_containers(Some(ListContainersOptions::<String>::default()))
+        .await?;
+    Ok(containers.into_iter().filter_map(|c| c.id).collect())
+}
+
+async fn stats_snapshot(docker: &Docker, containers: &[String], format: &str, sort: &str) -> bool {
+    let targets = if containers.is_empty() {
+        match running_containers(docker).await {
+            Ok(ids) => ids,
             Err(e) => {
-                eprintln!("{} Failed to connect to Docker: {}", "✗".red(), e);
-                return;
+                eprintln!("{} Failed to list containers: {}", "✗".red(), e);
+                return false;
             }
-        };
-
-        println!("\n{} {}", "Container Stats:".cyan().bold(), container_name);
-        println!("{}", "=".repeat(60));
+        }
+    } else {
+        containers.to_vec()
+    };
 
+    // A non-streaming sample still waits for a second reading, so CPU% is meaningful
+    let samples = targets.iter().map(|target| async move {
         let options = Some(StatsOptions {
             stream: false,
-            one_shot: true,
+            one_shot: false,
         });
+        let sample = docker.stats(target, options).next().await;
+        (target, sample)
+    });
 
-        let mut stream = docker.stats(container_name, options);
+    let mut ok = true;
+    let mut rows = Vec::new();
+    for (target, sample) in futures::future::join_all(samples).await {
+        match sample {
+            Some(Ok(stats)) => rows.push(StatsRow::from_stats(&stats)),
+            Some(Err(e)) => {
+                eprintln!("{} {}: {}", "✗".red(), target, e);
+                ok = false;
+            }
+            None => {
+                eprintln!("{} Container not found: {}", "✗".red(), target);
+                ok = false;
+            }
+        }
+    }
 
-        if let Some(result) = stream.next().await {
-            match result {
-                Ok(stats) => {
-                    // CPU
-                    let cpu_delta = stats.cpu_stats.cpu_usage.total_usage
-                        - stats.precpu_stats.cpu_usage.total_usage;
-                    let system_delta = stats.cpu_stats.system_cpu_usage.unwrap_or(0)
-                        - stats.precpu_stats.system_cpu_usage.unwrap_or(0);
-                    let cpu_percent = if system_delta > 0 {
-                        (cpu_delta as f64 / system_delta as f64) * 100.0
-                    } else {
-                        0.0
-                    };
+    sort_stats(&mut rows, sort);
 
-                    // Memory
-                    let mem_usage = stats.memory_stats.usage.unwrap_or(0) as f64 / 1024.0 / 1024.0;
-                    let mem_limit = stats.memory_stats.limit.unwrap_or(0) as f64 / 1024.0 / 1024.0;
-                    let mem_percent = if mem_limit > 0.0 {
-                        (mem_usage / mem_limit) * 100.0
-                    } else {
-                        0.0
-                    };
+    if format == "json" {
+        match serde_json::to_string_pretty(&rows) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("{} Failed to serialize stats: {}", "✗".red(), e);
+                return false;
+            }
+        }
+    } else {
+        println!("\n{}", "Container Stats".cyan().bold());
+        println!("{}", "=".repeat(80));
+        if rows.is_empty() {
+            println!("{} No running containers", "!".yellow());
+        } else {
+            println!("{}", stats_table(&rows));
+        }
+        println!();
+    }
 
-                    println!("  CPU Usage:    {:.2}%", cpu_percent);
-                    println!(
-                        "  Memory Usage: {:.2} MB / {:.2} MB ({:.2}%)",
-                        mem_usage, mem_limit, mem_percent
-                    );
+    ok
+}
 
-                    // Network
-                    if let Some(networks) = stats.networks {
-                        let mut total_rx = 0u64;
-                        let mut total_tx = 0u64;
-                        for (_, net) in networks {
-                            total_rx += net.rx_bytes;
-                            total_tx += net.tx_bytes;
-                        }
-                        println!(
-                            "  Network RX:   {:.2} MB",
-                            total_rx as f64 / 1024.0 / 1024.0
-                        );
-                        println!(
-                            "  Network TX:   {:.2} MB",
-                            total_tx as f64 / 1024.0 / 1024.0
-                        );
-                    }
+async fn stats_live(docker: &Docker, containers: &[String], sort: &str) -> bool {
+    // Unknown names would otherwise be retried every second without a word
+    for container in containers {
+        if let Err(e) = docker.inspect_container(container, None).await {
+            eprintln!("{} {}: {}", "✗".red(), container, e);
+            return false;
+        }
+    }
 
-                    println!();
-                }
-                Err(e) => {
-                    eprintln!("{} Failed to get stats: {}", "✗".red(), e);
+    let rows: Arc<Mutex<HashMap<String, StatsRow>>> = Arc::new(Mutex::new(HashMap::new()));
+    // Watchers drop out when their stream ends, so a restarted container is picked up again
+    let watched: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+
+    let render = async {
+        let mut stdout = std::io::stdout();
+        let _ = crossterm::execute!(stdout, crossterm::cursor::Hide);
+
+        loop {
+            // Without explicit targets, pick up containers as they start
+            let targets = if containers.is_empty() {
+                running_containers(docker).await.unwrap_or_default()
+            } else {
+                containers.to_vec()
+            };
+
+            for target in targets {
+                let is_new = watched
+                    .lock()
+                    .map(|mut w| w.insert(target.clone()))
+                    .unwrap_or(false);
+                if is_new {
+                    tokio::spawn(watch_stats(
+                        docker.clone(),
+                        target,
+                        rows.clone(),
+                        watched.clone(),
+                    ));
                 }
             }
-        } else {
-            eprintln!("{} Container not found: {}", "✗".red(), container_name);
+
+            let mut snapshot: Vec<StatsRow> = rows
+                .lock()
+                .map(|r| r.values().cloned().collect())
+                .unwrap_or_default();
+            sort_stats(&mut snapshot, sort);
+
+            let _ = crossterm::execute!(
+                stdout,
+                crossterm::cursor::MoveTo(0, 0),
+                crossterm::terminal::Clear(crossterm::terminal::ClearType::All)
+            );
+            println!(
+                "{}  {}",
+                "Container Stats".cyan().bold(),
+                format!(
+                    "{} · sorted by {} · Ctrl-C to quit",
+                    chrono::Local::now().format("%H:%M:%S"),
+                    sort
+                )
+                .dimmed()
+            );
+            if snapshot.is_empty() {
+                println!("{} No running containers", "!".yellow());
+            } else {
+                println!("{}", stats_table(&snapshot));
+            }
+
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
         }
+    };
+
+    let render = Box::pin(render);
+    let interrupted = Box::pin(tokio::signal::ctrl_c());
+    futures::future::select(render, interrupted).await;
+
+    let _ = crossterm::execute!(std::io::stdout(), crossterm::cursor::Show);
+    println!();
+    true
+}
+
+/// Stream samples for one container into `rows` until it stops, then leave `watched`
+async fn watch_stats(
+    docker: Docker,
+    target: String,
+    rows: Arc<Mutex<HashMap<String, StatsRow>>>,
+    watched: Arc<Mutex<HashSet<String>>>,
+) {
+    let options = Some(StatsOptions {
+        stream: true,
+        one_shot: false,
     });
+    let mut stream = docker.stats(&target, options);
+
+    while let Some(Ok(stats)) = stream.next().await {
+        if let Ok(mut rows) = rows.lock() {
+            rows.insert(target.clone(), StatsRow::from_stats(&stats));
+        }
+    }
+
+    if let Ok(mut rows) = rows.lock() {
+        rows.remove(&target);
+    }
+    if let Ok(mut watched) = watched.lock() {
+        watched.remove(&target);
+    }
 }
 
-pub fn logs(container_name: &str, lines: usize) {
-    let rt = match tokio::runtime::Runtime::new() {
-        Ok(r) => r,
-        Err(e) => {
-            eprintln!("{} Failed to create runtime: {}", "✗".red(), e);
-            return;
+fn sort_stats(rows: &mut [StatsRow], sort: &str) {
+    match sort {
+        "name" => rows.sort_by(|a, b| a.name.cmp(&b.name)),
+        "mem" => rows.sort_by_key(|r| std::cmp::Reverse(r.memory_usage)),
+        "net" => rows.sort_by_key(|r| std::cmp::Reverse(r.net_rx + r.net_tx)),
+        "block" => rows.sort_by_key(|r| std::cmp::Reverse(r.block_read + r.block_write)),
+        "pids" => rows.sort_by_key(|r| std::cmp::Reverse(r.pids)),
+        _ => rows.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent)),
+    }
+}
+
+fn stats_table(rows: &[StatsRow]) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        Cell::new("Container").fg(Color::Cyan),
+        Cell::new("CPU %").fg(Color::Cyan),
+        Cell::new("Mem Usage / Limit").fg(Color::Cyan),
+        Cell::new("Mem %").fg(Color::Cyan),
+        Cell::new("Net I/O").fg(Color::Cyan),
+        Cell::new("Block I/O").fg(Color::Cyan),
+        Cell::new("PIDs").fg(Color::Cyan),
+    ]);
+
+    let load_color = |percent: f64| {
+        if percent >= 80.0 {
+            Color::Red
+        } else if percent >= 50.0 {
+            Color::Yellow
+        } else {
+            Color::Green
         }
     };
 
-    rt.block_on(async {
-        let docker = match Docker::connect_with_local_defaults() {
-            Ok(d) => d,
-            Err(e) => {
-                eprintln!("{} Failed to connect to Docker: {}", "✗".red(), e);
-                return;
+    for row in rows {
+        table.add_row(vec![
+            Cell::new(&row.name),
+            Cell::new(format!("{:.2}%", row.cpu_percent)).fg(load_color(row.cpu_percent)),
+            Cell::new(format!(
+                "{} / {}",
+                format_size(row.memory_usage),
+                format_size(row.memory_limit)
+            )),
+            Cell::new(format!("{:.2}%", row.memory_percent)).fg(load_color(row.memory_percent)),
+            Cell::new(format!(
+                "{} / {}",
+                format_size(row.net_rx),
+                format_size(row.net_tx)
+            )),
+            Cell::new(format!(
+                "{} / {}",
+                format_size(row.block_read),
+                format_size(row.block_write)
+            )),
+            Cell::new(row.pids),
+        ]);
+    }
+
+    table
+}
+
+/// Settings for [`logs`]
+#[derive(Debug, Default)]
+pub struct LogOptions {
+    /// Lines to show from the end of each log (0 shows everything)
+    pub tail: usize,
+    /// Keep streaming new output until Ctrl-C
+    pub follow: bool,
+    /// Start/end time: unix seconds, RFC 3339, YYYY-MM-DD or relative ("10m", "2h", "1d")
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub timestamps: bool,
+    /// Only show lines matching this regex (matches are highlighted)
+    pub grep: Option<String>,
+    /// Highlight matches without filtering
+    pub highlight: Option<String>,
+    /// Restrict output to one stream; both are shown when neither is set
+    pub stdout_only: bool,
+    pub stderr_only: bool,
+}
+
+/// Show logs for one or more containers, interleaved with per-container prefixes
+pub fn logs(containers: &[String], options: &LogOptions) -> bool {
+    let compile = |pattern: &Option<String>| match pattern {
+        Some(p) => Regex::new(p).map(Some),
+        None => Ok(None),
+    };
+    let (grep, highlight) = match (compile(&options.grep), compile(&options.highlight)) {
+        (Ok(g), Ok(h)) => (g, h),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{} Invalid regex: {}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let parse = |spec: &Option<String>| match spec {
+        Some(s) => parse_time_spec(s, now).map(Some).ok_or(s.clone()),
+        None => Ok(None),
+    };
+    let (since, until) = match (parse(&options.since), parse(&options.until)) {
+        (Ok(s), Ok(u)) => (s, u),
+        (Err(spec), _) | (_, Err(spec)) => {
+            eprintln!("{} Invalid time: {}", "✗".red(), spec);
+            eprintln!("  Use unix seconds, RFC 3339, YYYY-MM-DD or a duration like 10m, 2h, 1d");
+            return false;
+        }
+    };
+
+    with_docker(|docker| async move {
+        // Resolve names up front so a typo fails before any output
+        let mut names = Vec::new();
+        for container in containers {
+            match docker.inspect_container(container, None).await {
+                Ok(info) => names.push(
+                    info.name
+                        .map(|n| n.trim_start_matches('/').to_string())
+                        .unwrap_or_else(|| container.clone()),
+                ),
+                Err(e) => {
+                    eprintln!("{} {}: {}", "✗".red(), container, e);
+                    return false;
+                }
             }
-        };
+        }
 
-        println!(
-            "\n{} {} (last {} lines)",
-            "Container Logs:".cyan().bold(),
-            container_name,
-            lines
-        );
-        println!("{}", "=".repeat(60));
+        if !options.follow {
+            let tail = if options.tail == 0 {
+                "all".to_string()
+            } else {
+                format!("last {} lines", options.tail)
+            };
+            println!(
+                "\n{} {} ({})",
+                "Container Logs:".cyan().bold(),
+                names.join(", "),
+                tail
+            );
+            println!("{}", "=".repeat(60));
+        }
 
-        let options = Some(bollard::container::LogsOptions::<String> {
-            stdout: true,
-            stderr: true,
-            tail: lines.to_string(),
-            ..Default::default()
+        // Prefixes only matter once output from several containers is mixed
+        let width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+        let prefixes: Vec<Option<String>> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                (names.len() > 1).then(|| {
+                    let padded = format!("{:<width$} |", name, width = width);
+                    prefix_color(i, &padded).to_string()
+                })
+            })
+            .collect();
+
+        let streams = names.iter().enumerate().map(|(i, name)| {
+            let opts = bollard::container::LogsOptions::<String> {
+                follow: options.follow,
+                stdout: !options.stderr_only || options.stdout_only,
+                stderr: !options.stdout_only || options.stderr_only,
+                since: since.unwrap_or(0),
+                until: until.unwrap_or(0),
+                timestamps: options.timestamps,
+                tail: if options.tail == 0 {
+                    "all".to_string()
+                } else {
+                    options.tail.to_string()
+                },
+            };
+            docker.logs(name, Some(opts)).map(move |r| (i, r)).boxed()
         });
+        let mut merged = futures::stream::select_all(streams);
 
-        let mut stream = docker.logs(container_name, options);
+        let printer = LogPrinter {
+            prefixes: &prefixes,
+            timestamps: options.timestamps,
+            grep: grep.as_ref(),
+            highlight: highlight.as_ref(),
+        };
+        let mut buffers: HashMap<(usize, bool), String> = HashMap::new();
 
-        while let Some(result) = stream.next().await {
-            match result {
-                Ok(log) => {
-                    print!("{}", log);
-                }
-                Err(e) => {
-                    eprintln!("{} Error reading logs: {}", "✗".red(), e);
-                    break;
+        let read = async {
+            while let Some((i, result)) = merged.next().await {
+                match result {
+                    Ok(output) => {
+                        let is_err = matches!(output, LogOutput::StdErr { .. });
+                        let chunk = output.to_string();
+                        let buffer = buffers.entry((i, is_err)).or_default();
+                        for line in take_lines(buffer, &chunk) {
+                            printer.print(i, is_err, &line);
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("{} Error reading logs: {}", "✗".red(), e);
+                        return false;
+                    }
                 }
             }
+            true
+        };
+
+        let ok = {
+            let read = Box::pin(read);
+            let interrupted = Box::pin(tokio::signal::ctrl_c());
+            match futures::future::select(read, interrupted).await {
+                futures::future::Either::Left((ok, _)) => ok,
+                futures::future::Either::Right(_) => true,
+            }
+        };
+
+        // Output cut off mid-line still deserves to be shown
+        for ((i, is_err), rest) in buffers {
+            if !rest.is_empty() {
+                printer.print(i, is_err, &rest);
+            }
         }
 
         println!();
-    });
+        ok
+    })
+    .unwrap_or(false)
+}
+
+struct LogPrinter<'a> {
+    prefixes: &'a [Option<String>],
+    timestamps: bool,
+    grep: Option<&'a Regex>,
+    highlight: Option<&'a Regex>,
+}
+
+impl LogPrinter<'_> {
+    fn print(&self, container: usize, is_err: bool, line: &str) {
+        let line = line.trim_end_matches('\r');
+
+        let (timestamp, message) = match line.split_once(' ') {
+            Some((ts, message)) if self.timestamps => (Some(ts), message),
+            _ => (None, line),
+        };
+
+        if let Some(grep) = self.grep {
+            if !grep.is_match(message) {
+                return;
+            }
+        }
+
+        let mut rendered = String::new();
+        if let Some(prefix) = &self.prefixes[container] {
+            rendered.push_str(prefix);
+            rendered.push(' ');
+        }
+        if let Some(ts) = timestamp {
+            rendered.push_str(&ts.dimmed().to_string());
+            rendered.push(' ');
+        }
+        rendered.push_str(&render_message(
+            message,
+            self.grep.or(self.highlight),
+            is_err,
+        ));
+
+        if is_err {
+            eprintln!("{}", rendered);
+        } else {
+            println!("{}", rendered);
+        }
+    }
+}
+
+/// Color a log message: matches of `pattern` highlighted, stderr text in red
+fn render_message(message: &str, pattern: Option<&Regex>, is_err: bool) -> String {
+    let plain = |text: &str| {
+        if is_err {
+            text.red().to_string()
+        } else {
+            text.to_string()
+        }
+    };
+
+    message_segments(message, pattern)
+        .into_iter()
+        .map(|(text, highlighted)| {
+            if highlighted {
+                text.black().on_yellow().to_string()
+            } else {
+                plain(text)
+            }
+        })
+        .collect()
+}
+
+/// Split a message into (text, is_match) runs for highlighting
+fn message_segments<'a>(message: &'a str, pattern: Option<&Regex>) -> Vec<(&'a str, bool)> {
+    let pattern = match pattern {
+        Some(p) => p,
+        None => return vec![(message, false)],
+    };
+
+    let mut segments = Vec::new();
+    let mut last = 0;
+    for m in pattern.find_iter(message) {
+        if m.start() > last {
+            segments.push((&message[last..m.start()], false));
+        }
+        segments.push((m.as_str(), true));
+        last = m.end();
+    }
+    if last < message.len() || segments.is_empty() {
+        segments.push((&message[last..], false));
+    }
+    segments
+}
+
+fn prefix_color(index: usize, text: &str) -> colored::ColoredString {
+    match index % 6 {
+        0 => text.cyan(),
+        1 => text.yellow(),
+        2 => text.green(),
+        3 => text.magenta(),
+        4 => text.blue(),
+        _ => text.bright_red(),
+    }
+}
+
+/// Append `chunk` to `buffer` and return the complete lines, keeping any partial line buffered
+fn take_lines(buffer: &mut String, chunk: &str) -> Vec<String> {
+    buffer.push_str(chunk);
+
+    let mut lines: Vec<String> = Vec::new();
+    while let Some(pos) = buffer.find('\n') {
+        lines.push(buffer[..pos].to_string());
+        buffer.drain(..=pos);
+    }
+    lines
+}
+
+/// Parse a point in time as unix seconds: unix seconds, RFC 3339, YYYY-MM-DD (UTC),
+/// or a duration before `now` such as "30s", "10m", "2h", "1d" or "1w"
+fn parse_time_spec(spec: &str, now: i64) -> Option<i64> {
+    let spec = spec.trim();
+
+    if let Ok(seconds) = spec.parse::<i64>() {
+        return Some(seconds);
+    }
+
+    if let Ok(time) = chrono::DateTime::parse_from_rfc3339(spec) {
+        return Some(time.timestamp());
+    }
+
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(spec, "%Y-%m-%d") {
+        return Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp());
+    }
+
+    parse_duration(spec).and_then(|seconds| now.checked_sub(seconds))
+}
+
+/// Parse a duration such as "90s", "10m", "2h", "1d" or "1w" into seconds
+fn parse_duration(spec: &str) -> Option<i64> {
+    let split = spec.find(|c: char| !c.is_ascii_digit())?;
+    let (number, unit) = spec.split_at(split);
+    let number: i64 = number.parse().ok()?;
+
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        "w" => 7 * 86400,
+        _ => return None,
+    };
+
+    number.checked_mul(multiplier)
 }
 
 fn format_ports(container: &ContainerSummary) -> String {
@@ -256,3 +981,3175 @@ fn format_ports(container: &ContainerSummary) -> String {
         "-".to_string()
     }
 }
+
+/// Lifecycle actions accepted by [`container_action`]
+pub const CONTAINER_ACTIONS: &[&str] =
+    &["start", "stop", "restart", "rm", "pause", "unpause", "kill"];
+
+/// Settings shared by the container lifecycle commands
+#[derive(Debug, Default)]
+pub struct ActionOptions {
+    /// Seconds to wait for a graceful stop before killing (stop, restart)
+    pub timeout: Option<i64>,
+    /// Signal to send (kill); SIGKILL when unset
+    pub signal: Option<String>,
+    /// Remove running containers (rm)
+    pub force: bool,
+    /// Remove anonymous volumes with the container (rm)
+    pub volumes: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Target {
+    id: String,
+    name: String,
+    state: String,
+}
+
+impl Target {
+    fn from_summary(container: &ContainerSummary) -> Self {
+        Self {
+            id: container.id.clone().unwrap_or_default(),
+            name: container_name(container).to_string(),
+            state: container.state.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Run a lifecycle action on containers selected by name, ID prefix or label selector
+pub fn container_action(
+    action: &str,
+    targets: &[String],
+    labels: &[String],
+    options: &ActionOptions,
+    yes: bool,
+) -> bool {
+    if !CONTAINER_ACTIONS.contains(&action) {
+        eprintln!("{} Unknown action: {}", "✗".red(), action);
+        return false;
+    }
+
+    if targets.is_empty() && labels.is_empty() {
+        eprintln!("{} No containers specified", "✗".red());
+        eprintln!(
+            "Usage: profilecore docker {} <container>... [--label key=value]",
+            action
+        );
+        return false;
+    }
+
+    with_docker(|docker| async move {
+        let selected = match find_targets(&docker, targets, labels).await {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("{} {}", "✗".red(), e);
+                return false;
+            }
+        };
+
+        if selected.is_empty() {
+            println!("{} No matching containers", "!".yellow());
+            return true;
+        }
+
+        if matches!(action, "rm" | "kill") && !yes {
+            println!("\n{}", "Containers:".cyan().bold());
+            for target in &selected {
+                println!("  {} ({})", target.name, target.state);
+            }
+            println!();
+
+            let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt(format!("{} {} container(s)?", action, selected.len()))
+                .default(false)
+                .interact()
+                .unwrap_or(false);
+            if !confirmed {
+                println!("{} Cancelled", "!".yellow());
+                return true;
+            }
+        }
+
+        let results = apply_action(&docker, action, &selected, options).await;
+
+        let mut ok = true;
+        for (target, result) in &results {
+            match result {
+                Ok(outcome) => println!(
+                    "{} {} ({}) {}",
+                    "✓".green(),
+                    target.name.cyan(),
+                    short_id(&target.id),
+                    outcome
+                ),
+                Err(e) => {
+                    eprintln!("{} {}: {}", "✗".red(), target.name, e);
+                    ok = false;
+                }
+            }
+        }
+
+        ok
+    })
+    .unwrap_or(false)
+}
+
+/// Resolve targets against all containers; label selectors add every container they match
+async fn find_targets(
+    docker: &Docker,
+    targets: &[String],
+    labels: &[String],
+) -> Result<Vec<Target>, String> {
+    let options = Some(ListContainersOptions::<String> {
+        all: true,
+        ..Default::default()
+    });
+
+    let containers = docker
+        .list_containers(options)
+        .await
+        .map_err(|e| format!("Failed to list containers: {}", e))?;
+
+    let mut selected = resolve_targets(&containers, targets)?;
+
+    if !labels.is_empty() {
+        for container in containers.iter().filter(|c| matches_labels(c, labels)) {
+            let target = Target::from_summary(container);
+            if !selected.contains(&target) {
+                selected.push(target);
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+/// Match each target by exact name, full ID or unambiguous ID prefix
+fn resolve_targets(
+    containers: &[ContainerSummary],
+    targets: &[String],
+) -> Result<Vec<Target>, String> {
+    let mut selected: Vec<Target> = Vec::new();
+
+    for target in targets {
+        let by_name = containers
+            .iter()
+            .find(|c| container_name(c) == target.trim_start_matches('/'));
+
+        let container = match by_name {
+            Some(c) => c,
+            None => {
+                let matches: Vec<&ContainerSummary> = containers
+                    .iter()
+                    .filter(|c| c.id.as_deref().unwrap_or("").starts_with(target.as_str()))
+                    .collect();
+
+                match matches.as_slice() {
+                    [c] => *c,
+                    [] => return Err(format!("No such container: {}", target)),
+                    _ => {
+                        return Err(format!(
+                            "Ambiguous ID prefix '{}' matches {} containers",
+                            target,
+                            matches.len()
+                        ))
+                    }
+                }
+            }
+        };
+
+        let target = Target::from_summary(container);
+        if !selected.contains(&target) {
+            selected.push(target);
+        }
+    }
+
+    Ok(selected)
+}
+
+/// Whether a container carries all `key` or `key=value` selectors
+fn matches_labels(container: &ContainerSummary, selectors: &[String]) -> bool {
+    let labels = match &container.labels {
+        Some(l) => l,
+        None => return false,
+    };
+
+    selectors.iter().all(|selector| has_label(labels, selector))
+}
+
+/// Whether `labels` satisfy a `key` or `key=value` selector
+fn has_label(labels: &HashMap<String, String>, selector: &str) -> bool {
+    match selector.split_once('=') {
+        Some((key, value)) => labels.get(key).map(|v| v == value).unwrap_or(false),
+        None => labels.contains_key(selector),
+    }
+}
+
+/// Apply `action` to every target concurrently, keeping the input order
+async fn apply_action(
+    docker: &Docker,
+    action: &str,
+    targets: &[Target],
+    options: &ActionOptions,
+) -> Vec<(Target, Result<String, String>)> {
+    let runs = targets.iter().map(|target| async move {
+        let id = target.id.as_str();
+        let result = match action {
+            "start" => docker.start_container::<String>(id, None).await,
+            "stop" => {
+                let opts = options.timeout.map(|t| StopContainerOptions { t });
+                docker.stop_container(id, opts).await
+            }
+            "restart" => {
+                let opts = options
+                    .timeout
+                    .map(|t| RestartContainerOptions { t: t as isize });
+                docker.restart_container(id, opts).await
+            }
+            "rm" => {
+                let opts = RemoveContainerOptions {
+                    v: options.volumes,
+                    force: options.force,
+                    ..Default::default()
+                };
+                docker.remove_container(id, Some(opts)).await
+            }
+            "pause" => docker.pause_container(id).await,
+            "unpause" => docker.unpause_container(id).await,
+            _ => {
+                let signal = options.signal.as_deref().unwrap_or("SIGKILL");
+                docker
+                    .kill_container(id, Some(KillContainerOptions { signal }))
+                    .await
+            }
+        };
+
+        // The engine answers 304 Not Modified (a success) when nothing changed
+        let unchanged = matches!(
+            (action, target.state.as_str()),
+            ("start", "running") | ("stop", "exited" | "created") | ("pause", "paused")
+        );
+
+        let outcome = match result {
+            Ok(()) if unchanged => Ok(format!("already {}", action_past_tense(action))),
+            Ok(()) => Ok(action_past_tense(action).to_string()),
+            Err(DockerError::DockerResponseServerError { message, .. }) => Err(message),
+            Err(e) => Err(e.to_string()),
+        };
+
+        (target.clone(), outcome)
+    });
+
+    futures::future::join_all(runs).await
+}
+
+fn action_past_tense(action: &str) -> &str {
+    match action {
+        "start" => "started",
+        "stop" => "stopped",
+        "restart" => "restarted",
+        "rm" => "removed",
+        "pause" => "paused",
+        "unpause" => "unpaused",
+        _ => "killed",
+    }
+}
+
+fn container_name(container: &ContainerSummary) -> &str {
+    container
+        .names
+        .as_ref()
+        .and_then(|names| names.first())
+        .map(|n| n.trim_start_matches('/'))
+        .unwrap_or("-")
+}
+
+fn short_id(id: &str) -> &str {
+    if id.len() > 12 {
+        &id[..12]
+    } else {
+        id
+    }
+}
+
+/// Settings for running a command with [`exec`]
+#[derive(Debug, Default)]
+pub struct ExecOptions {
+    /// Forward stdin to the command
+    pub interactive: bool,
+    /// Allocate a pseudo-TTY and put the local terminal in raw mode
+    pub tty: bool,
+    pub user: Option<String>,
+    pub workdir: Option<String>,
+    /// Extra `KEY=value` environment variables
+    pub env: Vec<String>,
+}
+
+/// Run a command inside a running container. Returns the command's exit code.
+pub fn exec(container: &str, command: &[String], options: &ExecOptions) -> i64 {
+    if options.tty && !std::io::stdin().is_terminal() {
+        eprintln!("{} The input device is not a TTY", "✗".red());
+        return 1;
+    }
+
+    with_docker(|docker| async move {
+        match run_exec(&docker, container, command, options).await {
+            Ok(code) => code,
+            Err(e) => {
+                eprintln!("{} {}", "✗".red(), e);
+                1
+            }
+        }
+    })
+    .unwrap_or(1)
+}
+
+async fn run_exec(
+    docker: &Docker,
+    container: &str,
+    command: &[String],
+    options: &ExecOptions,
+) -> Result<i64, DockerError> {
+    let config = CreateExecOptions {
+        attach_stdin: Some(options.interactive),
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        tty: Some(options.tty),
+        cmd: Some(command.to_vec()),
+        env: (!options.env.is_empty()).then(|| options.env.clone()),
+        user: options.user.clone(),
+        working_dir: options.workdir.clone(),
+        ..Default::default()
+    };
+
+    let exec_id = docker.create_exec(container, config).await?.id;
+
+    let start = StartExecOptions {
+        detach: false,
+        tty: options.tty,
+        output_capacity: None,
+    };
+
+    if let StartExecResults::Attached {
+        mut output,
+        mut input,
+    } = docker.start_exec(&exec_id, Some(start)).await?
+    {
+        // Restores the terminal when dropped, including on early return
+        let _raw = if options.tty { RawMode::enable() } else { None };
+
+        if options.tty {
+            tokio::spawn(watch_terminal_size(docker.clone(), exec_id.clone()));
+        }
+
+        if options.interactive {
+            // Reading stdin blocks, so it gets a thread of its own
+            let (tx, mut rx) = futures::channel::mpsc::unbounded::<Vec<u8>>();
+            std::thread::spawn(move || {
+                let mut stdin = std::io::stdin();
+                let mut buf = [0u8; 1024];
+                while let Ok(n) = stdin.read(&mut buf) {
+                    if n == 0 || tx.unbounded_send(buf[..n].to_vec()).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            tokio::spawn(async move {
+                while let Some(bytes) = rx.next().await {
+                    if input.write_all(&bytes).await.is_err() {
+                        return;
+                    }
+                    let _ = input.flush().await;
+                }
+                // Local EOF closes the command's stdin
+                let _ = input.shutdown().await;
+            });
+        }
+
+        while let Some(message) = output.next().await {
+            match message? {
+                LogOutput::StdErr { message } => {
+                    let mut stderr = std::io::stderr();
+                    let _ = stderr.write_all(&message);
+                    let _ = stderr.flush();
+                }
+                LogOutput::StdOut { message } | LogOutput::Console { message } => {
+                    let mut stdout = std::io::stdout();
+                    let _ = stdout.write_all(&message);
+                    let _ = stdout.flush();
+                }
+                LogOutput::StdIn { .. } => {}
+            }
+        }
+    }
+
+    let inspect = docker.inspect_exec(&exec_id).await?;
+    Ok(inspect.exit_code.unwrap_or(0))
+}
+
+/// Keep the exec's TTY size in step with the local terminal
+async fn watch_terminal_size(docker: Docker, exec_id: String) {
+    let mut last = None;
+    loop {
+        if let Ok((width, height)) = crossterm::terminal::size() {
+            if last != Some((width, height)) {
+                let options = ResizeExecOptions { height, width };
+                // Fails until the exec has started; retried on the next tick
+                if docker.resize_exec(&exec_id, options).await.is_ok() {
+                    last = Some((width, height));
+                }
+            }
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    }
+}
+
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> Option<Self> {
+        crossterm::terminal::enable_raw_mode().ok().map(|_| RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+pub fn images(all: bool, dangling: bool) -> bool {
+    with_docker(|docker| async move {
+        let mut filters = HashMap::new();
+        if dangling {
+            filters.insert("dangling".to_string(), vec!["true".to_string()]);
+        }
+        let options = Some(ListImagesOptions {
+            all,
+            filters,
+            ..Default::default()
+        });
+
+        let mut images = match docker.list_images(options).await {
+            Ok(i) => i,
+            Err(e) => {
+                eprintln!("{} Failed to list images: {}", "✗".red(), e);
+                return false;
+            }
+        };
+
+        if images.is_empty() {
+            println!("{} No images found", "!".yellow());
+            return true;
+        }
+
+        images.sort_by_key(|i| std::cmp::Reverse(i.created));
+
+        println!("\n{}", "Docker Images".cyan().bold());
+        println!("{}", "=".repeat(80));
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            Cell::new("Repository").fg(Color::Cyan),
+            Cell::new("Tag").fg(Color::Cyan),
+            Cell::new("ID").fg(Color::Cyan),
+            Cell::new("Created").fg(Color::Cyan),
+            Cell::new("Size").fg(Color::Cyan),
+        ]);
+
+        let mut total = 0;
+        for image in &images {
+            total += image.size.max(0) as u64;
+
+            let tags: Vec<&String> = image
+                .repo_tags
+                .iter()
+                .filter(|t| t.as_str() != "<none>:<none>")
+                .collect();
+
+            // Untagged images are listed once, flagged as dangling
+            let names: Vec<(String, String)> = if tags.is_empty() {
+                vec![("<none>".to_string(), "<none>".to_string())]
+            } else {
+                tags.iter()
+                    .map(|t| {
+                        let (repo, tag) = split_image_tag(t);
+                        (repo.to_string(), tag.to_string())
+                    })
+                    .collect()
+            };
+
+            for (repo, tag) in names {
+                let repo_cell = if tags.is_empty() {
+                    Cell::new(repo).fg(Color::Yellow)
+                } else {
+                    Cell::new(repo)
+                };
+
+                table.add_row(vec![
+                    repo_cell,
+                    Cell::new(tag),
+                    Cell::new(short_image_id(&image.id)),
+                    Cell::new(relative_time(image.created)),
+                    Cell::new(format_size(image.size.max(0) as u64)),
+                ]);
+            }
+        }
+
+        println!("{}", table);
+        println!("{} images, {} total\n", images.len(), format_size(total));
+        true
+    })
+    .unwrap_or(false)
+}
+
+pub fn volumes() -> bool {
+    with_docker(|docker| async move {
+        // Sizes are only reported by the disk usage endpoint
+        let volumes = match docker.df().await {
+            Ok(usage) => usage.volumes.unwrap_or_default(),
+            Err(_) => match docker.list_volumes::<String>(None).await {
+                Ok(list) => list.volumes.unwrap_or_default(),
+                Err(e) => {
+                    eprintln!("{} Failed to list volumes: {}", "✗".red(), e);
+                    return false;
+                }
+            },
+        };
+
+        if volumes.is_empty() {
+            println!("{} No volumes found", "!".yellow());
+            return true;
+        }
+
+        let containers = docker
+            .list_containers(Some(ListContainersOptions::<String> {
+                all: true,
+                ..Default::default()
+            }))
+            .await
+            .unwrap_or_default();
+        let users = volume_users(&containers);
+
+        println!("\n{}", "Docker Volumes".cyan().bold());
+        println!("{}", "=".repeat(80));
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            Cell::new("Name").fg(Color::Cyan),
+            Cell::new("Driver").fg(Color::Cyan),
+            Cell::new("Mountpoint").fg(Color::Cyan),
+            Cell::new("Size").fg(Color::Cyan),
+            Cell::new("Used By").fg(Color::Cyan),
+        ]);
+
+        for volume in &volumes {
+            let size = volume
+                .usage_data
+                .as_ref()
+                .filter(|u| u.size >= 0)
+                .map(|u| format_size(u.size as u64))
+                .unwrap_or_else(|| "-".to_string());
+
+            let used_by = match users.get(&volume.name) {
+                Some(names) => Cell::new(names.join(", ")).fg(Color::Green),
+                None => Cell::new("unused").fg(Color::Yellow),
+            };
+
+            table.add_row(vec![
+                Cell::new(&volume.name),
+                Cell::new(&volume.driver),
+                Cell::new(&volume.mountpoint),
+                Cell::new(size),
+                used_by,
+            ]);
+        }
+
+        println!("{}\n", table);
+        true
+    })
+    .unwrap_or(false)
+}
+
+pub fn networks() -> bool {
+    with_docker(|docker| async move {
+        let networks = match docker.list_networks::<String>(None).await {
+            Ok(n) => n,
+            Err(e) => {
+                eprintln!("{} Failed to list networks: {}", "✗".red(), e);
+                return false;
+            }
+        };
+
+        if networks.is_empty() {
+            println!("{} No networks found", "!".yellow());
+            return true;
+        }
+
+        println!("\n{}", "Docker Networks".cyan().bold());
+        println!("{}", "=".repeat(80));
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            Cell::new("Name").fg(Color::Cyan),
+            Cell::new("ID").fg(Color::Cyan),
+            Cell::new("Driver").fg(Color::Cyan),
+            Cell::new("Scope").fg(Color::Cyan),
+            Cell::new("Subnets").fg(Color::Cyan),
+            Cell::new("Containers").fg(Color::Cyan),
+        ]);
+
+        for network in networks {
+            let id = network.id.clone().unwrap_or_default();
+
+            // Attached containers are only filled in when inspecting a network
+            let network = docker
+                .inspect_network::<String>(&id, None)
+                .await
+                .unwrap_or(network);
+
+            let mut attached: Vec<String> = network
+                .containers
+                .iter()
+                .flatten()
+                .map(|(id, c)| c.name.clone().unwrap_or_else(|| short_id(id).to_string()))
+                .collect();
+            attached.sort();
+
+            table.add_row(vec![
+                Cell::new(network.name.as_deref().unwrap_or("")).fg(Color::Yellow),
+                Cell::new(short_id(&id)),
+                Cell::new(network.driver.as_deref().unwrap_or("")),
+                Cell::new(network.scope.as_deref().unwrap_or("")),
+                Cell::new(network_subnets(&network).join(", ")),
+                Cell::new(if attached.is_empty() {
+                    "-".to_string()
+                } else {
+                    attached.join(", ")
+                }),
+            ]);
+        }
+
+        println!("{}\n", table);
+        true
+    })
+    .unwrap_or(false)
+}
+
+/// Object kinds `inspect` can look up
+pub const INSPECT_KINDS: &[&str] = &["container", "image", "volume", "network"];
+
+/// Show a container, image, volume or network, trying each kind in turn unless `kind` is given
+pub fn inspect(target: &str, kind: Option<&str>, json: bool) -> bool {
+    if let Some(kind) = kind {
+        if !INSPECT_KINDS.contains(&kind) {
+            eprintln!("{} Unknown type: {}", "✗".red(), kind);
+            eprintln!("Types: {}", INSPECT_KINDS.join(", "));
+            return false;
+        }
+    }
+
+    with_docker(|docker| async move {
+        let (value, inspected) = match find_object(&docker, target, kind).await {
+            Ok(Some(found)) => found,
+            Ok(None) => {
+                eprintln!("{} No such object: {}", "✗".red(), target);
+                return false;
+            }
+            Err(e) => {
+                eprintln!("{} Failed to inspect {}: {}", "✗".red(), target, e);
+                return false;
+            }
+        };
+
+        if json {
+            return match value.and_then(|v| serde_json::to_string_pretty(&v)) {
+                Ok(text) => {
+                    println!("{}", text);
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{} Failed to serialize: {}", "✗".red(), e);
+                    false
+                }
+            };
+        }
+
+        match inspected {
+            Inspected::Container(c) => print_container(&c),
+            Inspected::Image(i) => print_image(&i),
+            Inspected::Volume(v) => print_volume(&v),
+            Inspected::Network(n) => print_network(&n),
+        }
+        true
+    })
+    .unwrap_or(false)
+}
+
+/// Look `target` up as each wanted object kind in turn. Only "not found" moves on
+/// to the next kind; any other engine error is returned.
+async fn find_object(
+    docker: &Docker,
+    target: &str,
+    kind: Option<&str>,
+) -> Result<Option<(serde_json::Result<serde_json::Value>, Inspected)>, DockerError> {
+    let wants = |k: &str| kind.map(|kind| kind == k).unwrap_or(true);
+
+    if wants("container") {
+        if let Some(c) = not_found_as_none(docker.inspect_container(target, None).await)? {
+            return Ok(Some((
+                serde_json::to_value(&c),
+                Inspected::Container(Box::new(c)),
+            )));
+        }
+    }
+    if wants("image") {
+        if let Some(i) = not_found_as_none(docker.inspect_image(target).await)? {
+            return Ok(Some((
+                serde_json::to_value(&i),
+                Inspected::Image(Box::new(i)),
+            )));
+        }
+    }
+    if wants("volume") {
+        if let Some(v) = not_found_as_none(docker.inspect_volume(target).await)? {
+            return Ok(Some((
+                serde_json::to_value(&v),
+                Inspected::Volume(Box::new(v)),
+            )));
+        }
+    }
+    if wants("network") {
+        if let Some(n) = not_found_as_none(docker.inspect_network::<String>(target, None).await)? {
+            return Ok(Some((
+                serde_json::to_value(&n),
+                Inspected::Network(Box::new(n)),
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+fn not_found_as_none<T>(result: Result<T, DockerError>) -> Result<Option<T>, DockerError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(DockerError::DockerResponseServerError {
+            status_code: 404, ..
+        }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+enum Inspected {
+    Container(Box<ContainerInspectResponse>),
+    Image(Box<ImageInspect>),
+    Volume(Box<Volume>),
+    Network(Box<Network>),
+}
+
+/// Print an aligned "label: value" line, skipping empty values
+fn field(label: &str, value: impl std::fmt::Display) {
+    let value = value.to_string();
+    if !value.is_empty() {
+        println!("  {:<14} {}", format!("{}:", label).bold(), value);
+    }
+}
+
+fn print_container(container: &ContainerInspectResponse) {
+    let name = container
+        .name
+        .as_deref()
+        .unwrap_or("")
+        .trim_start_matches('/');
+    println!("\n{} {}", "Container:".cyan().bold(), name);
+    println!("{}", "=".repeat(60));
+
+    let config = container.config.clone().unwrap_or_default();
+    let state = container.state.clone().unwrap_or_default();
+
+    field("ID", short_id(container.id.as_deref().unwrap_or("")));
+    field("Image", config.image.as_deref().unwrap_or(""));
+
+    let status = state.status.map(|s| s.to_string()).unwrap_or_default();
+    let status = match status.as_str() {
+        "running" => status.green(),
+        "exited" | "dead" => status.red(),
+        _ => status.yellow(),
+    };
+    field("Status", status);
+    if let Some(health) = state.health.and_then(|h| h.status) {
+        field("Health", health);
+    }
+    if !state.running.unwrap_or(false) {
+        field("Exit code", state.exit_code.unwrap_or(0));
+    }
+    field("Created", format_timestamp(container.created.as_deref()));
+    field("Started", format_timestamp(state.started_at.as_deref()));
+
+    let command: Vec<String> = config
+        .entrypoint
+        .unwrap_or_default()
+        .into_iter()
+        .chain(config.cmd.unwrap_or_default())
+        .collect();
+    field("Command", command.join(" "));
+    field("Workdir", config.working_dir.unwrap_or_default());
+
+    if let Some(policy) = container
+        .host_config
+        .as_ref()
+        .and_then(|h| h.restart_policy.as_ref())
+        .and_then(|p| p.name)
+    {
+        field("Restart", policy);
+    }
+
+    let settings = container.network_settings.clone().unwrap_or_default();
+    let mut ports: Vec<String> = settings
+        .ports
+        .unwrap_or_default()
+        .into_iter()
+        .map(
+            |(port, bindings)| match bindings.unwrap_or_default().first() {
+                Some(b) => format!(
+                    "{}:{} → {}",
+                    b.host_ip.as_deref().unwrap_or(""),
+                    b.host_port.as_deref().unwrap_or(""),
+                    port
+                ),
+                None => port,
+            },
+        )
+        .collect();
+    ports.sort();
+    field("Ports", ports.join(", "));
+
+    let networks: Vec<String> = settings
+        .networks
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, endpoint)| format!("{} ({})", name, endpoint.ip_address.unwrap_or_default()))
+        .collect();
+    field("Networks", networks.join(", "));
+
+    let mounts = container.mounts.clone().unwrap_or_default();
+    if !mounts.is_empty() {
+        println!("\n{}", "Mounts:".bold());
+        for mount in mounts {
+            let source = mount.name.or(mount.source).unwrap_or_default();
+            let mode = if mount.rw.unwrap_or(true) { "rw" } else { "ro" };
+            println!(
+                "  {} → {} ({})",
+                source.cyan(),
+                mount.destination.unwrap_or_default(),
+                mode
+            );
+        }
+    }
+
+    let env = config.env.unwrap_or_default();
+    if !env.is_empty() {
+        println!("\n{}", "Environment:".bold());
+        for var in env {
+            println!("  {}", var);
+        }
+    }
+
+    print_labels(config.labels.as_ref());
+    println!();
+}
+
+fn print_image(image: &ImageInspect) {
+    let tags = image.repo_tags.clone().unwrap_or_default();
+    let title = tags
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "<none>".to_string());
+    println!("\n{} {}", "Image:".cyan().bold(), title);
+    println!("{}", "=".repeat(60));
+
+    field("ID", short_image_id(image.id.as_deref().unwrap_or("")));
+    field("Tags", tags.join(", "));
+    field("Created", format_timestamp(image.created.as_deref()));
+    field("Size", format_size(image.size.unwrap_or(0).max(0) as u64));
+    field(
+        "Platform",
+        format!(
+            "{}/{}",
+            image.os.as_deref().unwrap_or(""),
+            image.architecture.as_deref().unwrap_or("")
+        ),
+    );
+
+    let config = image.config.clone().unwrap_or_default();
+    field(
+        "Entrypoint",
+        config.entrypoint.unwrap_or_default().join(" "),
+    );
+    field("Cmd", config.cmd.unwrap_or_default().join(" "));
+    field("Workdir", config.working_dir.unwrap_or_default());
+    field("User", config.user.unwrap_or_default());
+
+    let mut exposed: Vec<String> = config
+        .exposed_ports
+        .unwrap_or_default()
+        .into_keys()
+        .collect();
+    exposed.sort();
+    field("Exposed", exposed.join(", "));
+
+    let layers = image
+        .root_fs
+        .as_ref()
+        .and_then(|r| r.layers.as_ref())
+        .map(|l| l.len())
+        .unwrap_or(0);
+    field("Layers", layers);
+
+    print_labels(config.labels.as_ref());
+    println!();
+}
+
+fn print_volume(volume: &Volume) {
+    println!("\n{} {}", "Volume:".cyan().bold(), volume.name);
+    println!("{}", "=".repeat(60));
+
+    field("Driver", &volume.driver);
+    field("Mountpoint", &volume.mountpoint);
+    field("Created", format_timestamp(volume.created_at.as_deref()));
+    if let Some(scope) = volume.scope {
+        field("Scope", scope);
+    }
+
+    let mut options: Vec<String> = volume
+        .options
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    options.sort();
+    field("Options", options.join(", "));
+
+    print_labels(Some(&volume.labels));
+    println!();
+}
+
+fn print_network(network: &Network) {
+    println!(
+        "\n{} {}",
+        "Network:".cyan().bold(),
+        network.name.as_deref().unwrap_or("")
+    );
+    println!("{}", "=".repeat(60));
+
+    field("ID", short_id(network.id.as_deref().unwrap_or("")));
+    field("Driver", network.driver.as_deref().unwrap_or(""));
+    field("Scope", network.scope.as_deref().unwrap_or(""));
+    field("Subnets", network_subnets(network).join(", "));
+
+    let gateways: Vec<String> = network
+        .ipam
+        .iter()
+        .flat_map(|ipam| ipam.config.iter().flatten())
+        .filter_map(|c| c.gateway.clone())
+        .collect();
+    field("Gateway", gateways.join(", "));
+    field("Internal", network.internal.unwrap_or(false));
+    field("Created", format_timestamp(network.created.as_deref()));
+
+    let containers = network.containers.clone().unwrap_or_default();
+    if !containers.is_empty() {
+        println!("\n{}", "Containers:".bold());
+        for (id, container) in containers {
+            println!(
+                "  {} {}",
+                container
+                    .name
+                    .unwrap_or_else(|| short_id(&id).to_string())
+                    .cyan(),
+                container.ipv4_address.unwrap_or_default()
+            );
+        }
+    }
+
+    print_labels(network.labels.as_ref());
+    println!();
+}
+
+fn print_labels(labels: Option<&HashMap<String, String>>) {
+    let mut labels: Vec<(&String, &String)> = labels.into_iter().flatten().collect();
+    if labels.is_empty() {
+        return;
+    }
+
+    labels.sort();
+    println!("\n{}", "Labels:".bold());
+    for (key, value) in labels {
+        println!("  {}={}", key.dimmed(), value);
+    }
+}
+
+/// Render an RFC 3339 timestamp as local time with its age
+fn format_timestamp(value: Option<&str>) -> String {
+    match value.and_then(|v| chrono::DateTime::parse_from_rfc3339(v).ok()) {
+        // Docker reports "0001-01-01T00:00:00Z" for times that never happened
+        Some(time) if time.timestamp() > 0 => format!(
+            "{} ({})",
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S"),
+            relative_time(time.timestamp())
+        ),
+        _ => String::new(),
+    }
+}
+
+fn network_subnets(network: &Network) -> Vec<String> {
+    network
+        .ipam
+        .iter()
+        .flat_map(|ipam| ipam.config.iter().flatten())
+        .filter_map(|c| c.subnet.clone())
+        .collect()
+}
+
+/// Map volume names to the containers mounting them
+fn volume_users(containers: &[ContainerSummary]) -> HashMap<String, Vec<String>> {
+    let mut users: HashMap<String, Vec<String>> = HashMap::new();
+
+    for container in containers {
+        for mount in container.mounts.iter().flatten() {
+            if let Some(volume) = &mount.name {
+                users
+                    .entry(volume.clone())
+                    .or_default()
+                    .push(container_name(container).to_string());
+            }
+        }
+    }
+
+    users
+}
+
+/// Split "registry:5000/app:1.2" into ("registry:5000/app", "1.2")
+fn split_image_tag(reference: &str) -> (&str, &str) {
+    let reference = reference.split('@').next().unwrap_or(reference);
+    match reference.rsplit_once(':') {
+        // A colon before the last slash belongs to a registry port, not a tag
+        Some((repo, tag)) if !tag.contains('/') => (repo, tag),
+        _ => (reference, "latest"),
+    }
+}
+
+fn short_image_id(id: &str) -> &str {
+    short_id(id.strip_prefix("sha256:").unwrap_or(id))
+}
+
+/// Space used by one kind of Docker object
+#[derive(Debug, PartialEq)]
+struct UsageRow {
+    kind: &'static str,
+    total: usize,
+    active: usize,
+    size: u64,
+    reclaimable: u64,
+}
+
+fn summarize_usage(usage: &SystemDataUsageResponse) -> Vec<UsageRow> {
+    let images = usage.images.as_deref().unwrap_or_default();
+    let image_size = usage
+        .layers_size
+        .map(|s| s.max(0) as u64)
+        .unwrap_or_else(|| images.iter().map(|i| i.size.max(0) as u64).sum());
+    // Layers shared with images still in use aren't freed by removing an image
+    let unused_images: u64 = images
+        .iter()
+        .filter(|i| i.containers <= 0)
+        .map(|i| (i.size - i.shared_size.max(0)).max(0) as u64)
+        .sum();
+
+    let containers = usage.containers.as_deref().unwrap_or_default();
+    let container_size = |c: &ContainerSummary| c.size_rw.unwrap_or(0).max(0) as u64;
+    let is_running = |c: &ContainerSummary| c.state.as_deref() == Some("running");
+
+    let volumes = usage.volumes.as_deref().unwrap_or_default();
+    let volume_size = |v: &Volume| {
+        v.usage_data
+            .as_ref()
+            .map(|u| u.size.max(0) as u64)
+            .unwrap_or(0)
+    };
+    let volume_used = |v: &Volume| {
+        v.usage_data
+            .as_ref()
+            .map(|u| u.ref_count > 0)
+            .unwrap_or(false)
+    };
+
+    let cache = usage.build_cache.as_deref().unwrap_or_default();
+    let cache_size = |c: &BuildCache| c.size.unwrap_or(0).max(0) as u64;
+    let cache_used = |c: &BuildCache| c.in_use.unwrap_or(false);
+
+    vec![
+        UsageRow {
+            kind: "Images",
+            total: images.len(),
+            active: images.iter().filter(|i| i.containers > 0).count(),
+            size: image_size,
+            reclaimable: unused_images.min(image_size),
+        },
+        UsageRow {
+            kind: "Containers",
+            total: containers.len(),
+            active: containers.iter().filter(|c| is_running(c)).count(),
+            size: containers.iter().map(container_size).sum(),
+            reclaimable: containers
+                .iter()
+                .filter(|c| !is_running(c))
+                .map(container_size)
+                .sum(),
+        },
+        UsageRow {
+            kind: "Local Volumes",
+            total: volumes.len(),
+            active: volumes.iter().filter(|v| volume_used(v)).count(),
+            size: volumes.iter().map(volume_size).sum(),
+            reclaimable: volumes
+                .iter()
+                .filter(|v| !volume_used(v))
+                .map(volume_size)
+                .sum(),
+        },
+        UsageRow {
+            kind: "Build Cache",
+            total: cache.len(),
+            active: cache.iter().filter(|c| cache_used(c)).count(),
+            size: cache.iter().map(cache_size).sum(),
+            reclaimable: cache
+                .iter()
+                .filter(|c| !cache_used(c) && !c.shared.unwrap_or(false))
+                .map(cache_size)
+                .sum(),
+        },
+    ]
+}
+
+/// Summarize disk space used by images, containers, volumes and build cache
+pub fn df() -> bool {
+    with_docker(|docker| async move {
+        let usage = match docker.df().await {
+            Ok(u) => u,
+            Err(e) => {
+                eprintln!("{} Failed to get disk usage: {}", "✗".red(), e);
+                return false;
+            }
+        };
+
+        println!("\n{}", "Docker Disk Usage".cyan().bold());
+        println!("{}", "=".repeat(60));
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            Cell::new("Type").fg(Color::Cyan),
+            Cell::new("Total").fg(Color::Cyan),
+            Cell::new("Active").fg(Color::Cyan),
+            Cell::new("Size").fg(Color::Cyan),
+            Cell::new("Reclaimable").fg(Color::Cyan),
+        ]);
+
+        let rows = summarize_usage(&usage);
+        for row in &rows {
+            let percent = if row.size > 0 {
+                row.reclaimable as f64 / row.size as f64 * 100.0
+            } else {
+                0.0
+            };
+
+            table.add_row(vec![
+                Cell::new(row.kind).fg(Color::Yellow),
+                Cell::new(row.total),
+                Cell::new(row.active),
+                Cell::new(format_size(row.size)),
+                Cell::new(format!(
+                    "{} ({:.0}%)",
+                    format_size(row.reclaimable),
+                    percent
+                )),
+            ]);
+        }
+
+        println!("{}", table);
+
+        let reclaimable: u64 = rows.iter().map(|r| r.reclaimable).sum();
+        if reclaimable > 0 {
+            println!(
+                "{} {} can be reclaimed with `profilecore docker prune`",
+                "!".yellow(),
+                format_size(reclaimable)
+            );
+        }
+        println!();
+        true
+    })
+    .unwrap_or(false)
+}
+
+/// What [`prune`] should remove
+#[derive(Debug, Default)]
+pub struct PruneOptions {
+    /// Dangling (untagged, unused) images
+    pub images: bool,
+    /// Stopped containers
+    pub containers: bool,
+    /// Anonymous volumes not used by any container
+    pub volumes: bool,
+    /// With `volumes`, named volumes too (`docker volume prune --all`)
+    pub all_volumes: bool,
+    pub build_cache: bool,
+    /// Only remove objects created longer ago than this (e.g. "24h", "7d")
+    pub older_than: Option<String>,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PruneCandidate {
+    kind: &'static str,
+    id: String,
+    name: String,
+    size: u64,
+    created: i64,
+}
+
+/// Pick prune candidates of the selected kinds, skipping anything newer than `cutoff`
+/// or carrying one of the `keep` label selectors
+fn prune_candidates(
+    usage: &SystemDataUsageResponse,
+    options: &PruneOptions,
+    cutoff: Option<i64>,
+    keep: &[String],
+) -> Vec<PruneCandidate> {
+    let old_enough = |created: i64| cutoff.map(|c| created < c).unwrap_or(true);
+    let kept = |labels: Option<&HashMap<String, String>>| {
+        labels
+            .map(|l| keep.iter().any(|selector| has_label(l, selector)))
+            .unwrap_or(false)
+    };
+
+    let mut candidates = Vec::new();
+
+    if options.containers {
+        for container in usage.containers.iter().flatten() {
+            let stopped = matches!(
+                container.state.as_deref(),
+                Some("exited" | "created" | "dead")
+            );
+            let created = container.created.unwrap_or(0);
+            if stopped && old_enough(created) && !kept(container.labels.as_ref()) {
+                candidates.push(PruneCandidate {
+                    kind: "container",
+                    id: container.id.clone().unwrap_or_default(),
+                    name: container_name(container).to_string(),
+                    size: container.size_rw.unwrap_or(0).max(0) as u64,
+                    created,
+                });
+            }
+        }
+    }
+
+    if options.images {
+        for image in usage.images.iter().flatten() {
+            let dangling = image.repo_tags.iter().all(|t| t == "<none>:<none>");
+            if dangling
+                && image.containers <= 0
+                && old_enough(image.created)
+                && !kept(Some(&image.labels))
+            {
+                candidates.push(PruneCandidate {
+                    kind: "image",
+                    id: image.id.clone(),
+                    name: short_image_id(&image.id).to_string(),
+                    size: image.size.max(0) as u64,
+                    created: image.created,
+                });
+            }
+        }
+    }
+
+    if options.volumes {
+        for volume in usage.volumes.iter().flatten() {
+            let unused = volume
+                .usage_data
+                .as_ref()
+                .map(|u| u.ref_count == 0)
+                .unwrap_or(false);
+            let created = parse_docker_time(volume.created_at.as_deref());
+            if unused
+                && (options.all_volumes || is_anonymous_volume(volume))
+                && old_enough(created)
+                && !kept(Some(&volume.labels))
+            {
+                candidates.push(PruneCandidate {
+                    kind: "volume",
+                    id: volume.name.clone(),
+                    name: volume.name.clone(),
+                    size: volume
+                        .usage_data
+                        .as_ref()
+                        .map(|u| u.size.max(0) as u64)
+                        .unwrap_or(0),
+                    created,
+                });
+            }
+        }
+    }
+
+    if options.build_cache {
+        for cache in usage.build_cache.iter().flatten() {
+            let created = parse_docker_time(cache.created_at.as_deref());
+            if !cache.in_use.unwrap_or(false) && old_enough(created) {
+                candidates.push(PruneCandidate {
+                    kind: "build cache",
+                    id: cache.id.clone().unwrap_or_default(),
+                    name: cache
+                        .description
+                        .clone()
+                        .unwrap_or_else(|| short_id(cache.id.as_deref().unwrap_or("")).to_string()),
+                    size: cache.size.unwrap_or(0).max(0) as u64,
+                    created,
+                });
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Anonymous volumes have a random 64-hex-digit name; newer engines also label them
+fn is_anonymous_volume(volume: &Volume) -> bool {
+    volume.labels.contains_key("com.docker.volume.anonymous")
+        || (volume.name.len() == 64 && volume.name.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Remove unused Docker objects, showing what goes and how much space it frees
+pub fn prune(options: &PruneOptions) -> bool {
+    let cutoff = match &options.older_than {
+        Some(spec) => match parse_duration(spec) {
+            Some(seconds) => Some(chrono::Utc::now().timestamp() - seconds),
+            None => {
+                eprintln!("{} Invalid duration: {}", "✗".red(), spec);
+                eprintln!("  Use a duration like 12h, 7d or 2w");
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    let settings = match DockerSettings::load() {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{} {}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    with_docker(|docker| async move {
+        let usage = match docker.df().await {
+            Ok(u) => u,
+            Err(e) => {
+                eprintln!("{} Failed to get disk usage: {}", "✗".red(), e);
+                return false;
+            }
+        };
+
+        let keep = &settings.prune.keep_labels;
+        let candidates = prune_candidates(&usage, options, cutoff, keep);
+
+        println!("\n{}", "Docker Prune".cyan().bold());
+        println!("{}", "=".repeat(60));
+
+        if !keep.is_empty() {
+            println!("Keeping objects labelled: {}\n", keep.join(", ").cyan());
+        }
+
+        if candidates.is_empty() {
+            println!("{} Nothing to prune\n", "✓".green());
+            return true;
+        }
+
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL);
+        table.set_header(vec![
+            Cell::new("Type").fg(Color::Cyan),
+            Cell::new("Name").fg(Color::Cyan),
+            Cell::new("Size").fg(Color::Cyan),
+            Cell::new("Created").fg(Color::Cyan),
+        ]);
+        for candidate in &candidates {
+            table.add_row(vec![
+                Cell::new(candidate.kind).fg(Color::Yellow),
+                Cell::new(&candidate.name),
+                Cell::new(format_size(candidate.size)),
+                Cell::new(if candidate.created > 0 {
+                    relative_time(candidate.created)
+                } else {
+                    "-".to_string()
+                }),
+            ]);
+        }
+        println!("{}", table);
+
+        let reclaimable: u64 = candidates.iter().map(|c| c.size).sum();
+        println!(
+            "{} object(s), {} reclaimable\n",
+            candidates.len(),
+            format_size(reclaimable).green()
+        );
+
+        if options.dry_run {
+            println!("{} Dry run: nothing was removed", "!".yellow());
+            return true;
+        }
+
+        if !options.yes {
+            let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt(format!("Remove {} object(s)?", candidates.len()))
+                .default(false)
+                .interact()
+                .unwrap_or(false);
+            if !confirmed {
+                println!("{} Cancelled", "!".yellow());
+                return true;
+            }
+        }
+
+        let mut removed = 0;
+        let mut reclaimed = 0;
+        let mut ok = true;
+
+        for candidate in candidates.iter().filter(|c| c.kind != "build cache") {
+            let result = match candidate.kind {
+                "container" => docker.remove_container(&candidate.id, None).await,
+                "image" => docker
+                    .remove_image(&candidate.id, None, None)
+                    .await
+                    .map(|_| ()),
+                _ => docker.remove_volume(&candidate.id, None).await,
+            };
+
+            match result {
+                Ok(()) => {
+                    removed += 1;
+                    reclaimed += candidate.size;
+                }
+                Err(e) => {
+                    eprintln!("{} {} {}: {}", "✗".red(), candidate.kind, candidate.name, e);
+                    ok = false;
+                }
+            }
+        }
+
+        // bollard has no build cache endpoint, so this goes through the docker CLI
+        let cache: Vec<&PruneCandidate> = candidates
+            .iter()
+            .filter(|c| c.kind == "build cache")
+            .collect();
+        if !cache.is_empty() {
+            let mut command = match resolve_endpoint() {
+                Ok(endpoint) => docker_cli(&endpoint),
+                Err(e) => {
+                    eprintln!("{} Failed to select Docker endpoint: {:#}", "✗".red(), e);
+                    return false;
+                }
+            };
+            // --all removes every unused entry, as listed above, not just dangling ones
+            command.args(["builder", "prune", "--all", "--force"]);
+            // `until` takes Go durations or timestamps, so pass the cutoff itself
+            if let Some(cutoff) = cutoff {
+                command.args(["--filter", &format!("until={}", cutoff)]);
+            }
+
+            // The CLI chooses what it removes, so report its total rather than our list
+            match command.output() {
+                Ok(output) if output.status.success() => {
+                    match cache_reclaimed(&String::from_utf8_lossy(&output.stdout)) {
+                        Some(total) => {
+                            println!("{} Pruned build cache, reclaimed {}", "✓".green(), total)
+                        }
+                        None => println!("{} Pruned build cache", "✓".green()),
+                    }
+                }
+                Ok(output) => {
+                    eprintln!(
+                        "{} Failed to prune build cache: {}",
+                        "✗".red(),
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    );
+                    ok = false;
+                }
+                Err(e) => {
+                    eprintln!(
+                        "{} Pruning build cache needs the docker CLI: {}",
+                        "✗".red(),
+                        e
+                    );
+                    ok = false;
+                }
+            }
+        }
+
+        if cache.len() < candidates.len() {
+            println!(
+                "{} Removed {} object(s), reclaimed {}",
+                "✓".green(),
+                removed,
+                format_size(reclaimed)
+            );
+        }
+        println!();
+        ok
+    })
+    .unwrap_or(false)
+}
+
+/// Space reported by `docker builder prune` ("Total: 1.2GB" or "Total reclaimed space: 1.2GB")
+fn cache_reclaimed(output: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        let line = line.trim();
+        line.strip_prefix("Total reclaimed space:")
+            .or_else(|| line.strip_prefix("Total:"))
+            .map(|total| total.trim().to_string())
+            .filter(|total| !total.is_empty())
+    })
+}
+
+/// Unix seconds of an RFC 3339 timestamp, or 0 when missing
+fn parse_docker_time(value: Option<&str>) -> i64 {
+    value
+        .and_then(|v| chrono::DateTime::parse_from_rfc3339(v).ok())
+        .map(|t| t.timestamp())
+        .unwrap_or(0)
+}
+
+/// Compose project and service a container belongs to, if any
+fn compose_labels(container: &ContainerSummary) -> Option<(&str, &str)> {
+    let labels = container.labels.as_ref()?;
+    let project = labels.get(PROJECT_LABEL)?;
+    let service = labels.get(SERVICE_LABEL).map(|s| s.as_str()).unwrap_or("-");
+    Some((project, service))
+}
+
+/// Load the compose project for the current directory, reporting failures
+fn load_compose_project(file: Option<&str>, name: Option<&str>) -> Option<ComposeProject> {
+    let dir = match std::env::current_dir() {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{} Failed to get current directory: {}", "✗".red(), e);
+            return None;
+        }
+    };
+
+    match ComposeProject::load(&dir, file.map(std::path::Path::new), name) {
+        Ok(project) => Some(project),
+        Err(e) => {
+            eprintln!("{} {:#}", "✗".red(), e);
+            None
+        }
+    }
+}
+
+/// All containers (running or not) created for a compose project
+fn project_containers(project: &str) -> Option<Vec<ContainerSummary>> {
+    with_docker(|docker| async move {
+        let mut filters = HashMap::new();
+        filters.insert(
+            "label".to_string(),
+            vec![format!("{}={}", PROJECT_LABEL, project)],
+        );
+        let options = Some(ListContainersOptions {
+            all: true,
+            filters,
+            ..Default::default()
+        });
+
+        match docker.list_containers(options).await {
+            Ok(containers) => Some(containers),
+            Err(e) => {
+                eprintln!("{} Failed to list containers: {}", "✗".red(), e);
+                None
+            }
+        }
+    })
+    .flatten()
+}
+
+/// A service as declared in the compose file and as running
+#[derive(Debug)]
+struct ServiceStatus<'a> {
+    service: String,
+    /// Image or build context; `None` for containers of undeclared services
+    source: Option<String>,
+    replicas: u32,
+    containers: Vec<&'a ContainerSummary>,
+}
+
+impl ServiceStatus<'_> {
+    fn running(&self) -> usize {
+        self.containers
+            .iter()
+            .filter(|c| c.state.as_deref() == Some("running"))
+            .count()
+    }
+}
+
+/// Match declared services against the project's containers; leftovers from
+/// services no longer in the file are reported as undeclared
+fn compose_status<'a>(
+    project: &ComposeProject,
+    containers: &'a [ContainerSummary],
+) -> Vec<ServiceStatus<'a>> {
+    let mut statuses: Vec<ServiceStatus> = project
+        .file
+        .services
+        .iter()
+        .map(|(name, service)| ServiceStatus {
+            service: name.clone(),
+            source: Some(service.source()),
+            replicas: service.replicas(),
+            containers: Vec::new(),
+        })
+        .collect();
+
+    for container in containers {
+        let service = compose_labels(container).map(|(_, s)| s).unwrap_or("-");
+        match statuses.iter_mut().find(|s| s.service == service) {
+            Some(status) => status.containers.push(container),
+            None => statuses.push(ServiceStatus {
+                service: service.to_string(),
+                source: None,
+                replicas: 0,
+                containers: vec![container],
+            }),
+        }
+    }
+
+    statuses
+}
+
+/// Show each declared service next to the containers running it
+pub fn compose_ps(file: Option<&str>, name: Option<&str>) -> bool {
+    let project = match load_compose_project(file, name) {
+        Some(p) => p,
+        None => return false,
+    };
+    let containers = match project_containers(&project.name) {
+        Some(c) => c,
+        None => return false,
+    };
+
+    println!(
+        "\n{} {} ({})",
+        "Compose Project:".cyan().bold(),
+        project.name,
+        project.path.display()
+    );
+    println!("{}", "=".repeat(80));
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        Cell::new("Service").fg(Color::Cyan),
+        Cell::new("Source").fg(Color::Cyan),
+        Cell::new("Running").fg(Color::Cyan),
+        Cell::new("Container").fg(Color::Cyan),
+        Cell::new("Status").fg(Color::Cyan),
+        Cell::new("Ports").fg(Color::Cyan),
+    ]);
+
+    let statuses = compose_status(&project, &containers);
+    let mut up = 0;
+
+    for status in &statuses {
+        let running = status.running();
+        let healthy = status.source.is_some() && running >= status.replicas as usize;
+        if healthy {
+            up += 1;
+        }
+
+        let source = match &status.source {
+            Some(source) => Cell::new(source),
+            None => Cell::new("not declared").fg(Color::Yellow),
+        };
+        let running_cell = Cell::new(format!("{}/{}", running, status.replicas)).fg(if healthy {
+            Color::Green
+        } else if running > 0 {
+            Color::Yellow
+        } else {
+            Color::Red
+        });
+
+        if status.containers.is_empty() {
+            // Services behind a disabled profile aren't expected to exist
+            let state = if status.replicas == 0 {
+                Cell::new("profile not enabled").fg(Color::DarkGrey)
+            } else {
+                Cell::new("not created").fg(Color::Red)
+            };
+            table.add_row(vec![
+                Cell::new(&status.service).fg(Color::Yellow),
+                source,
+                running_cell,
+                Cell::new("-"),
+                state,
+                Cell::new("-"),
+            ]);
+            continue;
+        }
+
+        for (i, container) in status.containers.iter().enumerate() {
+            let state = container.state.as_deref().unwrap_or("");
+            let status_text = container.status.as_deref().unwrap_or("");
+            let status_cell = match state {
+                "running" => Cell::new(status_text).fg(Color::Green),
+                "exited" | "dead" => Cell::new(status_text).fg(Color::Red),
+                _ => Cell::new(status_text).fg(Color::Yellow),
+            };
+
+            // Service details only on the first of its containers
+            let (service, source, running_cell) = if i == 0 {
+                (
+                    Cell::new(&status.service).fg(Color::Yellow),
+                    source.clone(),
+                    running_cell.clone(),
+                )
+            } else {
+                (Cell::new(""), Cell::new(""), Cell::new(""))
+            };
+
+            table.add_row(vec![
+                service,
+                source,
+                running_cell,
+                Cell::new(container_name(container)),
+                status_cell,
+                Cell::new(format_ports(container)),
+            ]);
+        }
+    }
+
+    println!("{}", table);
+
+    let declared = project.file.services.len();
+    let icon = if up == declared {
+        "✓".green()
+    } else {
+        "!".yellow()
+    };
+    println!("{} {}/{} services up\n", icon, up, declared);
+    true
+}
+
+/// Names of the project's containers for `services` (all services when empty)
+fn service_containers(
+    project: &ComposeProject,
+    services: &[String],
+) -> Option<Vec<ContainerSummary>> {
+    for service in services {
+        if !project.file.services.contains_key(service) {
+            eprintln!("{} No such service: {}", "✗".red(), service);
+            return None;
+        }
+    }
+
+    let mut containers: Vec<ContainerSummary> = project_containers(&project.name)?
+        .into_iter()
+        .filter(|c| {
+            services.is_empty()
+                || compose_labels(c)
+                    .map(|(_, s)| services.iter().any(|wanted| wanted == s))
+                    .unwrap_or(false)
+        })
+        .collect();
+    containers.sort_by(|a, b| container_name(a).cmp(container_name(b)));
+
+    if containers.is_empty() {
+        eprintln!("{} No containers for project {}", "✗".red(), project.name);
+        return None;
+    }
+
+    Some(containers)
+}
+
+/// Logs for the project's services, interleaved like `docker compose logs`
+pub fn compose_logs(
+    file: Option<&str>,
+    name: Option<&str>,
+    services: &[String],
+    options: &LogOptions,
+) -> bool {
+    let project = match load_compose_project(file, name) {
+        Some(p) => p,
+        None => return false,
+    };
+
+    match service_containers(&project, services) {
+        Some(containers) => {
+            let names: Vec<String> = containers
+                .iter()
+                .map(|c| container_name(c).to_string())
+                .collect();
+            logs(&names, options)
+        }
+        None => false,
+    }
+}
+
+/// Restart the project's services (all when none are given)
+pub fn compose_restart(
+    file: Option<&str>,
+    name: Option<&str>,
+    services: &[String],
+    timeout: Option<i64>,
+) -> bool {
+    let project = match load_compose_project(file, name) {
+        Some(p) => p,
+        None => return false,
+    };
+
+    match service_containers(&project, services) {
+        Some(containers) => {
+            let ids: Vec<String> = containers.into_iter().filter_map(|c| c.id).collect();
+            let options = ActionOptions {
+                timeout,
+                ..Default::default()
+            };
+            container_action("restart", &ids, &[], &options, true)
+        }
+        None => false,
+    }
+}
+
+/// Filters for [`events`]; each list matches any of its values
+#[derive(Debug, Default)]
+pub struct EventFilters {
+    pub types: Vec<String>,
+    pub containers: Vec<String>,
+    pub images: Vec<String>,
+    pub labels: Vec<String>,
+    pub events: Vec<String>,
+}
+
+impl EventFilters {
+    /// Engine API filter map (`type`, `container`, `image`, `label`, `event`)
+    fn to_query(&self) -> HashMap<String, Vec<String>> {
+        [
+            ("type", &self.types),
+            ("container", &self.containers),
+            ("image", &self.images),
+            ("label", &self.labels),
+            ("event", &self.events),
+        ]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(key, values)| (key.to_string(), values.clone()))
+        .collect()
+    }
+}
+
+/// Stream engine events until Ctrl-C (or `until`), as readable lines or JSON lines
+pub fn events(
+    filters: &EventFilters,
+    since: Option<&str>,
+    until: Option<&str>,
+    format: &str,
+) -> bool {
+    if !matches!(format, "human" | "json") {
+        eprintln!("{} Unknown format: {}", "✗".red(), format);
+        eprintln!("Formats: human, json");
+        return false;
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let parse = |spec: Option<&str>| match spec {
+        Some(s) => parse_time_spec(s, now)
+            .map(|t| Some(t.to_string()))
+            .ok_or(s.to_string()),
+        None => Ok(None),
+    };
+    let (since, until) = match (parse(since), parse(until)) {
+        (Ok(s), Ok(u)) => (s, u),
+        (Err(spec), _) | (_, Err(spec)) => {
+            eprintln!("{} Invalid time: {}", "✗".red(), spec);
+            return false;
+        }
+    };
+
+    with_docker(|docker| async move {
+        if format == "human" {
+            eprintln!(
+                "{} {}",
+                "Docker Events".cyan().bold(),
+                "(Ctrl-C to stop)".dimmed()
+            );
+        }
+
+        let options = Some(EventsOptions {
+            since,
+            until,
+            filters: filters.to_query(),
+        });
+        let mut stream = docker.events(options);
+
+        let read = async {
+            while let Some(event) = stream.next().await {
+                match event {
+                    Ok(event) if format == "json" => match serde_json::to_string(&event) {
+                        Ok(line) => println!("{}", line),
+                        Err(e) => eprintln!("{} Failed to serialize event: {}", "✗".red(), e),
+                    },
+                    Ok(event) => println!("{}", format_event(&event)),
+                    Err(e) => {
+                        eprintln!("{} Event stream failed: {}", "✗".red(), e);
+                        return false;
+                    }
+                }
+            }
+            true
+        };
+
+        let read = Box::pin(read);
+        let interrupted = Box::pin(tokio::signal::ctrl_c());
+        match futures::future::select(read, interrupted).await {
+            futures::future::Either::Left((ok, _)) => ok,
+            futures::future::Either::Right(_) => true,
+        }
+    })
+    .unwrap_or(false)
+}
+
+/// One readable line: time, type, action, object name and notable attributes
+fn format_event(event: &EventMessage) -> String {
+    let time = event
+        .time
+        .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default();
+
+    let kind = event.typ.map(|t| t.to_string()).unwrap_or_default();
+    let action = event.action.clone().unwrap_or_default();
+
+    // "health_status: healthy" and "exec_start: sh" carry details after the colon
+    let verb = action.split(':').next().unwrap_or("").trim();
+    let action = match verb {
+        "start" | "create" | "unpause" | "restart" | "pull" | "tag" | "connect" | "mount" => {
+            action.green()
+        }
+        "die" | "kill" | "oom" | "stop" | "destroy" | "delete" | "untag" | "disconnect"
+        | "unmount" => action.red(),
+        _ if action.contains("unhealthy") => action.red(),
+        _ if action.contains("healthy") => action.green(),
+        _ => action.yellow(),
+    };
+
+    let actor = event.actor.clone().unwrap_or_default();
+    let id = actor.id.unwrap_or_default();
+    let attributes = actor.attributes.unwrap_or_default();
+    let name = attributes
+        .get("name")
+        .cloned()
+        .unwrap_or_else(|| short_id(&id).to_string());
+
+    let mut details: Vec<String> = ["image", "exitCode", "signal", "container", "type"]
+        .iter()
+        .filter_map(|key| attributes.get(*key).map(|v| format!("{}={}", key, v)))
+        .collect();
+    if let Some((project, service)) = attributes
+        .get(PROJECT_LABEL)
+        .zip(attributes.get(SERVICE_LABEL))
+    {
+        details.push(format!("compose={}/{}", project, service));
+    }
+
+    let mut line = format!(
+        "{} {:<9} {} {}",
+        time.dimmed(),
+        kind.cyan(),
+        action,
+        name.bold()
+    );
+    if !details.is_empty() {
+        line.push_str(&format!(
+            " {}",
+            format!("({})", details.join(", ")).dimmed()
+        ));
+    }
+    line
+}
+
+/// Where a container stands while waiting for it to become healthy
+#[derive(Debug, PartialEq)]
+enum HealthCheck {
+    Healthy,
+    Waiting(String),
+    Failed(String),
+}
+
+fn check_health(state: &ContainerState) -> HealthCheck {
+    let status = state.status.map(|s| s.to_string()).unwrap_or_default();
+    match state.status {
+        Some(
+            ContainerStateStatusEnum::EXITED
+            | ContainerStateStatusEnum::DEAD
+            | ContainerStateStatusEnum::REMOVING,
+        ) => {
+            return HealthCheck::Failed(format!(
+                "container is {} (exit code {})",
+                status,
+                state.exit_code.unwrap_or(0)
+            ))
+        }
+        // Freshly created or restarting containers (e.g. just after `compose up -d`)
+        _ if !state.running.unwrap_or(false) || state.restarting.unwrap_or(false) => {
+            return HealthCheck::Waiting(format!("container is {}", status));
+        }
+        _ => {}
+    }
+
+    match state.health.as_ref().and_then(|h| h.status) {
+        Some(HealthStatusEnum::HEALTHY) => HealthCheck::Healthy,
+        Some(HealthStatusEnum::STARTING) => HealthCheck::Waiting("starting".to_string()),
+        // A failing check may still recover before the timeout
+        Some(HealthStatusEnum::UNHEALTHY) => {
+            let streak = state
+                .health
+                .as_ref()
+                .and_then(|h| h.failing_streak)
+                .unwrap_or(0);
+            HealthCheck::Waiting(format!("unhealthy ({} failed checks)", streak))
+        }
+        _ => HealthCheck::Failed("container has no healthcheck".to_string()),
+    }
+}
+
+/// Block until a container's healthcheck reports healthy, failing after `timeout` seconds
+pub fn wait_healthy(container: &str, timeout: u64) -> bool {
+    with_docker(|docker| async move {
+        let spinner = ProgressBar::new_spinner();
+        spinner.set_style(
+            ProgressStyle::with_template("{spinner} {msg} [{elapsed}]")
+                .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+        );
+        spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(timeout);
+
+        loop {
+            let state = match docker.inspect_container(container, None).await {
+                Ok(info) => info.state.unwrap_or_default(),
+                Err(e) => {
+                    spinner.finish_and_clear();
+                    eprintln!("{} {}: {}", "✗".red(), container, e);
+                    return false;
+                }
+            };
+
+            match check_health(&state) {
+                HealthCheck::Healthy => {
+                    spinner.finish_and_clear();
+                    println!("{} {} is healthy", "✓".green(), container.cyan());
+                    return true;
+                }
+                HealthCheck::Failed(reason) => {
+                    spinner.finish_and_clear();
+                    eprintln!("{} {}: {}", "✗".red(), container, reason);
+                    return false;
+                }
+                HealthCheck::Waiting(status) => {
+                    if std::time::Instant::now() >= deadline {
+                        spinner.finish_and_clear();
+                        eprintln!(
+                            "{} {} not healthy after {}s (last status: {})",
+                            "✗".red(),
+                            container,
+                            timeout,
+                            status
+                        );
+                        return false;
+                    }
+                    spinner.set_message(format!("Waiting for {}: {}", container, status));
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        }
+    })
+    .unwrap_or(false)
+}
+
+/// What [`build`] should produce
+#[derive(Debug, Default)]
+pub struct BuildOptions {
+    pub context: String,
+    /// Dockerfile path relative to the current directory (default `<context>/Dockerfile`)
+    pub dockerfile: Option<String>,
+    /// Image names; the first is applied by the build, the rest tagged afterwards
+    pub tags: Vec<String>,
+    /// `KEY=VALUE`, or `KEY` to pass the value from the environment
+    pub build_args: Vec<String>,
+    /// Stop at this stage of a multi-stage Dockerfile
+    pub target: Option<String>,
+    pub no_cache: bool,
+    pub pull: bool,
+}
+
+/// `KEY=VALUE` pairs; a bare `KEY` takes its value from `env` and is dropped if unset
+fn parse_build_args(
+    args: &[String],
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<HashMap<String, String>, String> {
+    let mut parsed = HashMap::new();
+
+    for arg in args {
+        match arg.split_once('=') {
+            Some(("", _)) => return Err(format!("invalid build arg: {}", arg)),
+            Some((key, value)) => {
+                parsed.insert(key.to_string(), value.to_string());
+            }
+            None => {
+                if let Some(value) = env(arg) {
+                    parsed.insert(arg.clone(), value);
+                }
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// A line of classic builder output
+#[derive(Debug, PartialEq)]
+enum BuildLine<'a> {
+    Step {
+        current: u32,
+        total: u32,
+        instruction: &'a str,
+    },
+    /// Builder bookkeeping (` ---> 1a2b3c`, ` ---> Running in ...`)
+    Meta(&'a str),
+    Built(&'a str),
+    Skip,
+    Output(&'a str),
+}
+
+fn classify_build_line(line: &str) -> BuildLine<'_> {
+    if let Some(rest) = line.strip_prefix("Step ") {
+        if let Some((counter, instruction)) = rest.split_once(" : ") {
+            if let Some((current, total)) = counter.split_once('/') {
+                if let (Ok(current), Ok(total)) = (current.parse(), total.parse()) {
+                    return BuildLine::Step {
+                        current,
+                        total,
+                        instruction,
+                    };
+                }
+            }
+        }
+    }
+
+    if let Some(id) = line.strip_prefix("Successfully built ") {
+        return BuildLine::Built(id.trim());
+    }
+    if line.starts_with("Successfully tagged ")
+        || line.starts_with("Removing intermediate container ")
+        || line.trim().is_empty()
+    {
+        return BuildLine::Skip;
+    }
+    if let Some(meta) = line.strip_prefix(" ---> ") {
+        return BuildLine::Meta(meta);
+    }
+
+    BuildLine::Output(line)
+}
+
+/// Build an image from a local context, streaming the builder's output
+pub fn build(options: &BuildOptions) -> bool {
+    let context = match std::fs::canonicalize(&options.context) {
+        Ok(path) if path.is_dir() => path,
+        Ok(path) => {
+            eprintln!("{} {} is not a directory", "✗".red(), path.display());
+            return false;
+        }
+        Err(e) => {
+            eprintln!("{} {}: {}", "✗".red(), options.context, e);
+            return false;
+        }
+    };
+
+    let dockerfile = match &options.dockerfile {
+        Some(path) => std::path::PathBuf::from(path),
+        None => context.join("Dockerfile"),
+    };
+    let (dockerfile, mut contents) = match std::fs::canonicalize(&dockerfile)
+        .and_then(|path| std::fs::read_to_string(&path).map(|contents| (path, contents)))
+    {
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!("{} {}: {}", "✗".red(), dockerfile.display(), e);
+            return false;
+        }
+    };
+
+    // The engine's /build takes a `target`, but bollard 0.17's BuildImageOptions has no
+    // field for it, so send the Dockerfile only up to that stage
+    if let Some(target) = &options.target {
+        contents = match build_context::truncate_to_stage(&contents, target) {
+            Ok(truncated) => truncated,
+            Err(e) => {
+                eprintln!("{} {}", "✗".red(), e);
+                return false;
+            }
+        };
+    }
+
+    let build_args = match parse_build_args(&options.build_args, |key| std::env::var(key).ok()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{} {}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    let packed = match build_context::pack(&context, &dockerfile, &contents) {
+        Ok(packed) => packed,
+        Err(e) => {
+            eprintln!("{} Failed to pack build context: {:#}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    println!(
+        "{} Sending build context: {} files, {}",
+        "→".cyan(),
+        packed.files,
+        format_size(packed.archive.len() as u64)
+    );
+
+    with_docker(|docker| async move { run_build(&docker, packed, build_args, options).await })
+        .unwrap_or(false)
+}
+
+async fn run_build(
+    docker: &Docker,
+    packed: build_context::BuildContext,
+    build_args: HashMap<String, String>,
+    options: &BuildOptions,
+) -> bool {
+    let started = std::time::Instant::now();
+    let build_options = BuildImageOptions {
+        dockerfile: packed.dockerfile.clone(),
+        t: options.tags.first().cloned().unwrap_or_default(),
+        buildargs: build_args,
+        nocache: options.no_cache,
+        pull: options.pull,
+        rm: true,
+        ..Default::default()
+    };
+
+    let mut image_id = None;
+    let mut stream = docker.build_image(build_options, None, Some(packed.archive.into()));
+
+    while let Some(message) = stream.next().await {
+        let info = match message {
+            Ok(info) => info,
+            Err(e) => {
+                eprintln!("\n{} Build failed: {}", "✗".red(), e);
+                return false;
+            }
+        };
+
+        if let Some(error) = info.error {
+            eprintln!("\n{} Build failed: {}", "✗".red(), error.trim_end());
+            return false;
+        }
+
+        if let Some(id) = info.aux.and_then(|aux| aux.id) {
+            image_id = Some(id);
+        }
+
+        if let Some(text) = info.stream {
+            for line in text.lines() {
+                match classify_build_line(line) {
+                    BuildLine::Step {
+                        current,
+                        total,
+                        instruction,
+                    } => println!(
+                        "\n{} {}",
+                        format!("[{}/{}]", current, total).cyan().bold(),
+                        instruction.bold()
+                    ),
+                    BuildLine::Meta(meta) => println!("  {}", meta.dimmed()),
+                    BuildLine::Built(id) => {
+                        image_id.get_or_insert_with(|| id.to_string());
+                    }
+                    BuildLine::Skip => {}
+                    BuildLine::Output(output) => println!("  {}", output),
+                }
+            }
+        } else if let Some(status) = info.status {
+            // Pull progress arrives many times a second; only show state changes
+            if info.progress.is_none() {
+                match info.id {
+                    Some(id) => println!("  {}", format!("{}: {}", id, status).dimmed()),
+                    None => println!("  {}", status.dimmed()),
+                }
+            }
+        }
+    }
+
+    let image_id = match image_id {
+        Some(id) => id,
+        None => {
+            eprintln!("\n{} Build finished without an image ID", "✗".red());
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for tag in options.tags.iter().skip(1) {
+        let (repo, tag_name) = split_image_tag(tag);
+        let tag_options = TagImageOptions {
+            repo,
+            tag: tag_name,
+        };
+        if let Err(e) = docker.tag_image(&image_id, Some(tag_options)).await {
+            eprintln!("{} Failed to tag {}: {}", "✗".red(), tag, e);
+            ok = false;
+        }
+    }
+
+    let size = match docker.inspect_image(&image_id).await {
+        Ok(image) => image.size.map(|s| format_size(s as u64)),
+        Err(_) => None,
+    };
+
+    println!(
+        "\n{} Built {} in {:.1}s",
+        "✓".green(),
+        short_image_id(&image_id).cyan(),
+        started.elapsed().as_secs_f64()
+    );
+    if let Some(size) = size {
+        println!("  Size: {}", size);
+    }
+    if !options.tags.is_empty() {
+        println!("  Tags: {}", options.tags.join(", "));
+    }
+
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(unix)]
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    #[cfg(unix)]
+    use tokio::net::UnixListener;
+
+    fn summary(id: &str, name: &str, state: &str, labels: &[(&str, &str)]) -> ContainerSummary {
+        ContainerSummary {
+            id: Some(id.to_string()),
+            names: Some(vec![format!("/{}", name)]),
+            state: Some(state.to_string()),
+            labels: Some(
+                labels
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<HashMap<_, _>>(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn fixture() -> Vec<ContainerSummary> {
+        vec![
+            summary(
+                "3f2a1b9c0d1e",
+                "web",
+                "running",
+                &[("app", "shop"), ("tier", "front")],
+            ),
+            summary("3f9d8e7c6b5a", "db", "exited", &[("app", "shop")]),
+            summary("a1b2c3d4e5f6", "cache", "running", &[]),
+        ]
+    }
+
+    #[test]
+    fn test_resolve_targets() {
+        let containers = fixture();
+
+        let names = |targets: &[&str]| {
+            let targets: Vec<String> = targets.iter().map(|t| t.to_string()).collect();
+            resolve_targets(&containers, &targets)
+                .map(|found| found.into_iter().map(|t| t.name).collect::<Vec<_>>())
+        };
+
+        assert_eq!(names(&["web", "a1b2"]).unwrap(), vec!["web", "cache"]);
+        assert_eq!(names(&["/db", "3f9"]).unwrap(), vec!["db"]);
+        assert!(names(&["3f"]).unwrap_err().contains("Ambiguous"));
+        assert!(names(&["missing"])
+            .unwrap_err()
+            .contains("No such container"));
+    }
+
+    #[test]
+    fn test_parse_time_spec() {
+        let now = 1_700_000_000;
+        assert_eq!(parse_time_spec("1690000000", now), Some(1_690_000_000));
+        assert_eq!(parse_time_spec("10m", now), Some(now - 600));
+        assert_eq!(parse_time_spec("2h", now), Some(now - 7200));
+        assert_eq!(parse_time_spec("1d", now), Some(now - 86400));
+        assert_eq!(
+            parse_time_spec("2023-11-14T22:13:20Z", now),
+            Some(1_700_000_000)
+        );
+        assert_eq!(parse_time_spec("2023-11-14", now), Some(1_699_920_000));
+        assert_eq!(parse_time_spec("yesterday", now), None);
+        assert_eq!(parse_time_spec("5y", now), None);
+        assert_eq!(parse_time_spec("9999999999999999w", now), None);
+        assert_eq!(parse_duration("106751991167301d"), None);
+    }
+
+    #[test]
+    fn test_take_lines() {
+        let mut buffer = String::new();
+        assert_eq!(take_lines(&mut buffer, "one\ntw"), vec!["one"]);
+        assert_eq!(buffer, "tw");
+        assert_eq!(take_lines(&mut buffer, "o\nthree\n"), vec!["two", "three"]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_message_segments() {
+        let re = Regex::new("err\\w*").unwrap();
+        assert_eq!(
+            message_segments("an error here, errors", Some(&re)),
+            vec![
+                ("an ", false),
+                ("error", true),
+                (" here, ", false),
+                ("errors", true)
+            ]
+        );
+        assert_eq!(message_segments("error", Some(&re)), vec![("error", true)]);
+        assert_eq!(message_segments("plain", Some(&re)), vec![("plain", false)]);
+        assert_eq!(message_segments("plain", None), vec![("plain", false)]);
+    }
+
+    #[test]
+    fn test_stats_row_from_sample() {
+        let stats: Stats = serde_json::from_value(serde_json::json!({
+            "id": "3f2a1b9c0d1e",
+            "name": "/web",
+            "read": "2024-01-01T00:00:01Z",
+            "preread": "2024-01-01T00:00:00Z",
+            "num_procs": 0,
+            "pids_stats": { "current": 7 },
+            "networks": {
+                "eth0": { "rx_bytes": 1000, "tx_bytes": 500, "rx_dropped": 0, "rx_errors": 0,
+                          "rx_packets": 0, "tx_dropped": 0, "tx_errors": 0, "tx_packets": 0 },
+                "eth1": { "rx_bytes": 24, "tx_bytes": 12, "rx_dropped": 0, "rx_errors": 0,
+                          "rx_packets": 0, "tx_dropped": 0, "tx_errors": 0, "tx_packets": 0 }
+            },
+            "memory_stats": { "usage": 3000, "limit": 10000 },
+            "blkio_stats": {
+                "io_service_bytes_recursive": [
+                    { "major": 8, "minor": 0, "op": "read", "value": 4096 },
+                    { "major": 8, "minor": 0, "op": "write", "value": 512 }
+                ]
+            },
+            "cpu_stats": {
+                "cpu_usage": { "total_usage": 300, "usage_in_usermode": 0, "usage_in_kernelmode": 0 },
+                "system_cpu_usage": 2000,
+                "online_cpus": 2,
+                "throttling_data": { "periods": 0, "throttled_periods": 0, "throttled_time": 0 }
+            },
+            "precpu_stats": {
+                "cpu_usage": { "total_usage": 100, "usage_in_usermode": 0, "usage_in_kernelmode": 0 },
+                "system_cpu_usage": 1000,
+                "throttling_data": { "periods": 0, "throttled_periods": 0, "throttled_time": 0 }
+            },
+            "storage_stats": {}
+        }))
+        .unwrap();
+
+        let row = StatsRow::from_stats(&stats);
+        assert_eq!(row.name, "web");
+        assert!((row.cpu_percent - 40.0).abs() < 1e-9);
+        assert!((row.memory_percent - 30.0).abs() < 1e-9);
+        assert_eq!((row.net_rx, row.net_tx), (1024, 512));
+        assert_eq!((row.block_read, row.block_write), (4096, 512));
+        assert_eq!(row.pids, 7);
+    }
+
+    #[test]
+    fn test_sort_stats() {
+        let row = |name: &str, cpu: f64, mem: u64| StatsRow {
+            name: name.to_string(),
+            cpu_percent: cpu,
+            memory_usage: mem,
+            ..Default::default()
+        };
+        let mut rows = vec![row("b", 5.0, 300), row("a", 50.0, 100), row("c", 0.5, 200)];
+
+        sort_stats(&mut rows, "cpu");
+        assert_eq!(rows[0].name, "a");
+        sort_stats(&mut rows, "mem");
+        assert_eq!(rows[0].name, "b");
+        sort_stats(&mut rows, "name");
+        assert_eq!(rows[2].name, "c");
+    }
+
+    #[test]
+    fn test_split_image_tag() {
+        assert_eq!(split_image_tag("nginx:1.25"), ("nginx", "1.25"));
+        assert_eq!(split_image_tag("nginx"), ("nginx", "latest"));
+        assert_eq!(
+            split_image_tag("localhost:5000/team/app:dev"),
+            ("localhost:5000/team/app", "dev")
+        );
+        assert_eq!(
+            split_image_tag("localhost:5000/app"),
+            ("localhost:5000/app", "latest")
+        );
+        assert_eq!(
+            short_image_id("sha256:0123456789abcdef0123"),
+            "0123456789ab"
+        );
+    }
+
+    #[test]
+    fn test_volume_users() {
+        let mut web = summary("3f2a1b9c0d1e", "web", "running", &[]);
+        web.mounts = Some(vec![bollard::service::MountPoint {
+            name: Some("data".to_string()),
+            ..Default::default()
+        }]);
+        let mut worker = summary("3f9d8e7c6b5a", "worker", "exited", &[]);
+        worker.mounts = web.mounts.clone();
+
+        let users = volume_users(&[
+            web,
+            worker,
+            summary("a1b2c3d4e5f6", "cache", "running", &[]),
+        ]);
+        assert_eq!(users.len(), 1);
+        assert_eq!(users["data"], vec!["web", "worker"]);
+    }
+
+    fn usage_fixture() -> SystemDataUsageResponse {
+        serde_json::from_value(serde_json::json!({
+            "LayersSize": 1000,
+            "Images": [
+                { "Id": "sha256:aaa", "ParentId": "", "RepoTags": ["app:1"], "RepoDigests": [],
+                  "Created": 100, "Size": 600, "SharedSize": 100, "Labels": {}, "Containers": 1 },
+                { "Id": "sha256:bbb", "ParentId": "", "RepoTags": [], "RepoDigests": [],
+                  "Created": 100, "Size": 300, "SharedSize": 100, "Labels": {}, "Containers": 0 },
+                { "Id": "sha256:ccc", "ParentId": "", "RepoTags": ["<none>:<none>"], "RepoDigests": [],
+                  "Created": 5000, "Size": 100, "SharedSize": 0, "Labels": {}, "Containers": 0 }
+            ],
+            "Containers": [
+                { "Id": "c1", "Names": ["/web"], "State": "running", "SizeRw": 10, "Created": 100 },
+                { "Id": "c2", "Names": ["/old"], "State": "exited", "SizeRw": 20, "Created": 100 },
+                { "Id": "c3", "Names": ["/pinned"], "State": "exited", "SizeRw": 30, "Created": 100,
+                  "Labels": { "keep": "true" } }
+            ],
+            "Volumes": [
+                { "Name": "data", "Driver": "local", "Mountpoint": "/v/data", "Labels": {},
+                  "Scope": "local", "Options": {}, "UsageData": { "Size": 500, "RefCount": 1 } },
+                { "Name": "scratch", "Driver": "local", "Mountpoint": "/v/scratch", "Labels": {},
+                  "Scope": "local", "Options": {}, "UsageData": { "Size": 200, "RefCount": 0 } },
+                { "Name": "ab".repeat(32), "Driver": "local", "Mountpoint": "/v/anon",
+                  "Labels": { "com.docker.volume.anonymous": "" }, "Scope": "local",
+                  "Options": {}, "UsageData": { "Size": 30, "RefCount": 0 } }
+            ],
+            "BuildCache": [
+                { "ID": "b1", "InUse": false, "Shared": false, "Size": 40 },
+                { "ID": "b2", "InUse": true, "Shared": false, "Size": 60 }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_summarize_usage() {
+        let rows = summarize_usage(&usage_fixture());
+
+        assert_eq!(rows[0].kind, "Images");
+        assert_eq!((rows[0].total, rows[0].active), (3, 1));
+        assert_eq!((rows[0].size, rows[0].reclaimable), (1000, 300));
+        assert_eq!((rows[1].size, rows[1].reclaimable), (60, 50));
+        assert_eq!((rows[2].active, rows[2].reclaimable), (1, 230));
+        assert_eq!((rows[3].size, rows[3].reclaimable), (100, 40));
+    }
+
+    #[test]
+    fn test_docker_cli_targets_endpoint() {
+        let endpoint = Endpoint {
+            address: Address::Tcp("ci:2376".to_string()),
+            tls: Some(docker_host::TlsFiles {
+                ca: "/certs/ca.pem".into(),
+                cert: "/certs/cert.pem".into(),
+                key: "/certs/key.pem".into(),
+            }),
+            source: Source::HostFlag,
+        };
+        let command = docker_cli(&endpoint);
+
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, vec!["-H", "tcp://ci:2376"]);
+        let envs: HashMap<_, _> = command.get_envs().collect();
+        assert_eq!(
+            envs[std::ffi::OsStr::new("DOCKER_TLS_VERIFY")],
+            Some("1".as_ref())
+        );
+        assert_eq!(
+            envs[std::ffi::OsStr::new("DOCKER_CERT_PATH")],
+            Some("/certs".as_ref())
+        );
+        assert_eq!(envs[std::ffi::OsStr::new("DOCKER_HOST")], None);
+    }
+
+    #[test]
+    fn test_cache_reclaimed() {
+        assert_eq!(
+            cache_reclaimed("ID\tRECLAIMABLE\tSIZE\nabc123\ttrue\t12MB\nTotal:\t1.2GB\n"),
+            Some("1.2GB".to_string())
+        );
+        assert_eq!(
+            cache_reclaimed("Total reclaimed space: 0B\n"),
+            Some("0B".to_string())
+        );
+        assert_eq!(cache_reclaimed(""), None);
+    }
+
+    #[test]
+    fn test_prune_candidates() {
+        let usage = usage_fixture();
+        let keep = vec!["keep".to_string()];
+        let names = |options: &PruneOptions, cutoff: Option<i64>| {
+            prune_candidates(&usage, options, cutoff, &keep)
+                .into_iter()
+                .map(|c| c.name)
+                .collect::<Vec<_>>()
+        };
+
+        let everything = PruneOptions {
+            images: true,
+            containers: true,
+            volumes: true,
+            build_cache: true,
+            ..Default::default()
+        };
+        let anonymous = "ab".repeat(32);
+        assert_eq!(
+            names(&everything, None),
+            vec!["old", "bbb", "ccc", anonymous.as_str(), "b1"]
+        );
+
+        // Named volumes only go with --all-volumes
+        let all_volumes = PruneOptions {
+            volumes: true,
+            all_volumes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            names(&all_volumes, None),
+            vec!["scratch", anonymous.as_str()]
+        );
+
+        // Only images created before the cutoff are old enough
+        let images = PruneOptions {
+            images: true,
+            ..Default::default()
+        };
+        assert_eq!(names(&images, Some(1000)), vec!["bbb"]);
+    }
+
+    #[test]
+    fn test_compose_status() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("compose.yaml"),
+            "services:\n  web:\n    image: nginx\n    deploy:\n      replicas: 2\n  db:\n    image: postgres\n",
+        )
+        .unwrap();
+        let project = ComposeProject::load(dir.path(), None, Some("shop")).unwrap();
+
+        let labels = |service: &'static str| [(PROJECT_LABEL, "shop"), (SERVICE_LABEL, service)];
+        let containers = vec![
+            summary("1", "shop-web-1", "running", &labels("web")),
+            summary("2", "shop-web-2", "exited", &labels("web")),
+            summary("3", "shop-old-1", "running", &labels("old")),
+        ];
+
+        let statuses = compose_status(&project, &containers);
+        let find = |name: &str| statuses.iter().find(|s| s.service == name).unwrap();
+
+        assert_eq!(find("web").containers.len(), 2);
+        assert_eq!((find("web").running(), find("web").replicas), (1, 2));
+        assert!(find("db").containers.is_empty());
+        assert!(find("old").source.is_none());
+        assert_eq!(compose_labels(&containers[0]), Some(("shop", "web")));
+    }
+
+    #[test]
+    fn test_parse_build_args() {
+        let env = |key: &str| (key == "TOKEN").then(|| "from-env".to_string());
+        let args: Vec<String> = ["VERSION=1.2", "EMPTY=", "TOKEN", "UNSET"]
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+
+        let parsed = parse_build_args(&args, env).unwrap();
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed["VERSION"], "1.2");
+        assert_eq!(parsed["EMPTY"], "");
+        assert_eq!(parsed["TOKEN"], "from-env");
+
+        assert!(parse_build_args(&["=x".to_string()], env).is_err());
+    }
+
+    #[test]
+    fn test_classify_build_line() {
+        assert_eq!(
+            classify_build_line("Step 2/5 : RUN apk add curl"),
+            BuildLine::Step {
+                current: 2,
+                total: 5,
+                instruction: "RUN apk add curl"
+            }
+        );
+        assert_eq!(
+            classify_build_line(" ---> Running in 4f2a"),
+            BuildLine::Meta("Running in 4f2a")
+        );
+        assert_eq!(
+            classify_build_line("Successfully built 9c1d2e3f"),
+            BuildLine::Built("9c1d2e3f")
+        );
+        assert_eq!(
+            classify_build_line("Successfully tagged app:latest"),
+            BuildLine::Skip
+        );
+        assert_eq!(
+            classify_build_line("Step up: warming cache"),
+            BuildLine::Output("Step up: warming cache")
+        );
+    }
+
+    #[test]
+    fn test_event_filters() {
+        let filters = EventFilters {
+            types: vec!["container".to_string()],
+            events: vec!["start".to_string(), "die".to_string()],
+            ..Default::default()
+        };
+        let query = filters.to_query();
+
+        assert_eq!(query.len(), 2);
+        assert_eq!(query["type"], vec!["container"]);
+        assert_eq!(query["event"], vec!["start", "die"]);
+    }
+
+    #[test]
+    fn test_check_health() {
+        let state =
+            |value: serde_json::Value| -> ContainerState { serde_json::from_value(value).unwrap() };
+
+        assert_eq!(
+            check_health(&state(serde_json::json!({
+                "Running": true, "Health": { "Status": "healthy" }
+            }))),
+            HealthCheck::Healthy
+        );
+        assert_eq!(
+            check_health(&state(serde_json::json!({
+                "Running": true, "Health": { "Status": "unhealthy", "FailingStreak": 2 }
+            }))),
+            HealthCheck::Waiting("unhealthy (2 failed checks)".to_string())
+        );
+        assert!(matches!(
+            check_health(&state(serde_json::json!({ "Running": true }))),
+            HealthCheck::Failed(_)
+        ));
+        assert!(matches!(
+            check_health(&state(serde_json::json!({
+                "Running": false, "Status": "exited", "ExitCode": 1
+            }))),
+            HealthCheck::Failed(reason) if reason.contains("exit code 1")
+        ));
+        for status in ["dead", "removing"] {
+            assert!(matches!(
+                check_health(&state(
+                    serde_json::json!({ "Running": false, "Status": status })
+                )),
+                HealthCheck::Failed(_)
+            ));
+        }
+
+        assert_eq!(
+            check_health(&state(
+                serde_json::json!({ "Running": false, "Status": "created" })
+            )),
+            HealthCheck::Waiting("container is created".to_string())
+        );
+        assert_eq!(
+            check_health(&state(serde_json::json!({
+                "Running": true, "Restarting": true, "Status": "restarting",
+                "Health": { "Status": "unhealthy" }
+            }))),
+            HealthCheck::Waiting("container is restarting".to_string())
+        );
+    }
+
+    #[test]
+    fn test_matches_labels() {
+        let containers = fixture();
+        let selectors = |s: &[&str]| s.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+        assert!(matches_labels(
+            &containers[0],
+            &selectors(&["app=shop", "tier"])
+        ));
+        assert!(matches_labels(&containers[1], &selectors(&["app"])));
+        assert!(!matches_labels(
+            &containers[1],
+            &selectors(&["app=shop", "tier"])
+        ));
+        assert!(!matches_labels(&containers[2], &selectors(&["app"])));
+    }
+
+    /// Canned reply from the fake engine
+    #[cfg(unix)]
+    enum Reply {
+        Json(&'static str, String),
+        /// Hijack the connection (attach/exec) and write raw multiplexed frames
+        Upgrade(Vec<u8>),
+    }
+
+    /// Minimal Docker Engine API over a Unix socket, recording each request line
+    #[cfg(unix)]
+    async fn serve_fake_docker(
+        listener: UnixListener,
+        requests: Arc<Mutex<Vec<String>>>,
+        routes: fn(&str, &str) -> Reply,
+    ) {
+        while let Ok((stream, _)) = listener.accept().await {
+            let requests = requests.clone();
+
+            tokio::spawn(async move {
+                let mut stream = BufReader::new(stream);
+                loop {
+                    let mut request_line = String::new();
+                    if stream.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+                        return;
+                    }
+
+                    let mut content_length = 0;
+                    loop {
+                        let mut header = String::new();
+                        stream.read_line(&mut header).await.unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = header.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap_or(0);
+                            }
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    stream.read_exact(&mut body).await.unwrap();
+
+                    // "/v1.45/containers/json?all=true" -> "/containers/json"
+                    let mut parts = request_line.split_whitespace();
+                    let method = parts.next().unwrap_or("").to_string();
+                    let uri = parts.next().unwrap_or("");
+                    let uri = uri.split('?').next().unwrap_or("");
+                    // Drop an API version prefix such as /v1.45, but not /volumes
+                    let version = uri
+                        .strip_prefix("/v")
+                        .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()));
+                    let path = match version {
+                        Some(rest) => &rest[rest.find('/').unwrap_or(0)..],
+                        None => uri,
+                    };
+                    requests
+                        .lock()
+                        .unwrap()
+                        .push(format!("{} {}", method, path));
+
+                    match routes(&method, path) {
+                        Reply::Json(status, body) => {
+                            let response = format!(
+                                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                                status,
+                                body.len(),
+                                body
+                            );
+                            stream
+                                .get_mut()
+                                .write_all(response.as_bytes())
+                                .await
+                                .unwrap();
+                        }
+                        Reply::Upgrade(frames) => {
+                            let response = "HTTP/1.1 101 UPGRADED\r\nContent-Type: application/vnd.docker.raw-stream\r\nConnection: Upgrade\r\nUpgrade: tcp\r\n\r\n";
+                            let stream = stream.get_mut();
+                            stream.write_all(response.as_bytes()).await.unwrap();
+                            stream.write_all(&frames).await.unwrap();
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    /// Start a fake engine on a temporary socket and connect to it
+    #[cfg(unix)]
+    fn fake_docker(
+        routes: fn(&str, &str) -> Reply,
+    ) -> (tempfile::TempDir, Docker, Arc<Mutex<Vec<String>>>) {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("docker.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        tokio::spawn(serve_fake_docker(listener, requests.clone(), routes));
+
+        let docker =
+            Docker::connect_with_unix(socket.to_str().unwrap(), 5, bollard::API_DEFAULT_VERSION)
+                .unwrap();
+
+        (dir, docker, requests)
+    }
+
+    /// Frame in the engine's multiplexed stdout/stderr format
+    #[cfg(unix)]
+    fn frame(stream: u8, payload: &str) -> Vec<u8> {
+        let mut bytes = vec![stream, 0, 0, 0];
+        bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(payload.as_bytes());
+        bytes
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_watch_stats_releases_stopped_container() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let (_dir, docker, _requests) = fake_docker(|_, _| {
+                Reply::Json(
+                    "409 Conflict",
+                    r#"{"message":"container is not running"}"#.to_string(),
+                )
+            });
+
+            let rows = Arc::new(Mutex::new(HashMap::new()));
+            let watched = Arc::new(Mutex::new(HashSet::from(["web".to_string()])));
+            watch_stats(docker, "web".to_string(), rows.clone(), watched.clone()).await;
+
+            assert!(watched.lock().unwrap().is_empty());
+            assert!(rows.lock().unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_build_against_fake_api() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let (_dir, docker, requests) = fake_docker(|method, path| match (method, path) {
+                ("POST", "/build") => Reply::Json(
+                    "200 OK",
+                    [
+                        r#"{"stream":"Step 1/2 : FROM alpine\n"}"#,
+                        r#"{"stream":" ---> 1a2b3c4d5e6f\n"}"#,
+                        r#"{"stream":"Step 2/2 : RUN true\n"}"#,
+                        r#"{"aux":{"ID":"sha256:9f8e7d6c5b4a"}}"#,
+                        r#"{"stream":"Successfully built 9f8e7d6c5b4a\n"}"#,
+                    ]
+                    .join("\n"),
+                ),
+                ("POST", "/images/sha256:9f8e7d6c5b4a/tag") => {
+                    Reply::Json("201 Created", String::new())
+                }
+                ("GET", "/images/sha256:9f8e7d6c5b4a/json") => Reply::Json(
+                    "200 OK",
+                    r#"{"Id":"sha256:9f8e7d6c5b4a","Size":7340032}"#.to_string(),
+                ),
+                _ => Reply::Json("404 Not Found", r#"{"message":"not found"}"#.to_string()),
+            });
+
+            let options = BuildOptions {
+                tags: vec!["app:1.0".to_string(), "app:latest".to_string()],
+                ..Default::default()
+            };
+            let packed = build_context::BuildContext {
+                archive: Vec::new(),
+                dockerfile: "Dockerfile".to_string(),
+                files: 0,
+            };
+
+            assert!(run_build(&docker, packed, HashMap::new(), &options).await);
+
+            let requests = requests.lock().unwrap();
+            assert_eq!(
+                *requests,
+                vec![
+                    "POST /build",
+                    "POST /images/sha256:9f8e7d6c5b4a/tag",
+                    "GET /images/sha256:9f8e7d6c5b4a/json",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_stats_live_rejects_unknown_container() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let (_dir, docker, requests) = fake_docker(|_, _| {
+                Reply::Json(
+                    "404 Not Found",
+                    r#"{"message":"No such container: typo"}"#.to_string(),
+                )
+            });
+
+            assert!(!stats_live(&docker, &["typo".to_string()], "cpu").await);
+            assert_eq!(*requests.lock().unwrap(), vec!["GET /containers/typo/json"]);
+        });
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_find_object_against_fake_api() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let (_dir, docker, requests) = fake_docker(|method, path| match (method, path) {
+                ("GET", "/volumes/data") => Reply::Json(
+                    "200 OK",
+                    r#"{"Name":"data","Driver":"local","Mountpoint":"/var/lib/docker/volumes/data",
+                        "Labels":{},"Scope":"local","Options":{}}"#
+                        .to_string(),
+                ),
+                ("GET", "/images/locked/json") => Reply::Json(
+                    "403 Forbidden",
+                    r#"{"message":"permission denied"}"#.to_string(),
+                ),
+                _ => Reply::Json("404 Not Found", r#"{"message":"not found"}"#.to_string()),
+            });
+
+            let found = find_object(&docker, "data", None).await.unwrap();
+            assert!(matches!(found, Some((_, Inspected::Volume(_)))));
+            assert!(find_object(&docker, "missing", None)
+                .await
+                .unwrap()
+                .is_none());
+            assert!(find_object(&docker, "data", Some("network"))
+                .await
+                .unwrap()
+                .is_none());
+
+            // Anything but a 404 stops the search instead of reading as "not found"
+            assert!(find_object(&docker, "locked", None).await.is_err());
+
+            let requests = requests.lock().unwrap();
+            assert_eq!(
+                requests.last().map(String::as_str),
+                Some("GET /images/locked/json")
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_container_actions_against_fake_api() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let (_dir, docker, requests) = fake_docker(|method, path| match (method, path) {
+                ("GET", "/containers/json") => {
+                    Reply::Json("200 OK", serde_json::to_string(&fixture()).unwrap())
+                }
+                ("POST", "/containers/3f9d8e7c6b5a/stop") => {
+                    Reply::Json("304 Not Modified", String::new())
+                }
+                (_, p) if p.starts_with("/containers/a1b2c3d4e5f6") => Reply::Json(
+                    "409 Conflict",
+                    r#"{"message":"container is paused"}"#.to_string(),
+                ),
+                _ => Reply::Json("204 No Content", String::new()),
+            });
+
+            let targets = find_targets(&docker, &["cache".to_string()], &["app=shop".to_string()])
+                .await
+                .unwrap();
+            let names: Vec<&str> = targets.iter().map(|t| t.name.as_str()).collect();
+            assert_eq!(names, vec!["cache", "web", "db"]);
+
+            let options = ActionOptions {
+                timeout: Some(3),
+                ..Default::default()
+            };
+            let results = apply_action(&docker, "stop", &targets, &options).await;
+
+            assert_eq!(results[0].1, Err("container is paused".to_string()));
+            assert_eq!(results[1].1, Ok("stopped".to_string()));
+            assert_eq!(results[2].1, Ok("already stopped".to_string()));
+
+            let requests = requests.lock().unwrap();
+            assert!(requests.contains(&"POST /containers/3f2a1b9c0d1e/stop".to_string()));
+        });
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_exec_propagates_exit_code() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            let (_dir, docker, requests) = fake_docker(|method, path| match (method, path) {
+                ("POST", "/containers/web/exec") => {
+                    Reply::Json("201 Created", r#"{"Id":"e1"}"#.to_string())
+                }
+                ("POST", "/exec/e1/start") => {
+                    Reply::Upgrade([frame(1, "hello\n"), frame(2, "oops\n")].concat())
+                }
+                ("GET", "/exec/e1/json") => {
+                    Reply::Json("200 OK", r#"{"ExitCode":3,"Running":false}"#.to_string())
+                }
+                _ => Reply::Json("404 Not Found", r#"{"message":"not found"}"#.to_string()),
+            });
+
+            let command = vec!["false".to_string()];
+            let code = run_exec(&docker, "web", &command, &ExecOptions::default())
+                .await
+                .unwrap();
+            assert_eq!(code, 3);
+
+            let requests = requests.lock().unwrap();
+            assert_eq!(requests.last().unwrap(), "GET /exec/e1/json");
+        });
+    }
+}
@@ -1,5 +1,6 @@
 //! File operations
 
+use crate::utils::fs_helpers::format_size;
 use colored::Colorize;
 use regex;
 use sha2::{Digest, Sha256};
@@ -137,38 +138,6 @@ fn calculate_dir_size(path: &Path) -> io::Result<(u64, usize)> {
     Ok((total_size, file_count))
 }
 
-fn format_size(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-    const TB: u64 = GB * 1024;
-
-    if bytes >= TB {
-        format!("{:.2} TB", bytes as f64 / TB as f64)
-    } else if bytes >= GB {
-        format!("{:.2} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{} bytes", bytes)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_format_size() {
-        assert_eq!(format_size(512), "512 bytes");
-        assert_eq!(format_size(1024), "1.00 KB");
-        assert_eq!(format_size(1024 * 1024), "1.00 MB");
-        assert_eq!(format_size(1024 * 1024 * 1024), "1.00 GB");
-    }
-}
-
 pub fn find(pattern: &str, directory: &str) {
     let path = Path::new(directory);
 
@@ -1,5 +1,7 @@
-//! Security tools (rustls, argon2, bcrypt, rand, zxcvbn)
+//! Security tools (rustls, argon2, bcrypt, rand, zxcvbn, secret scanning)
 
+use crate::config::SecretsConfig;
+use crate::utils::secrets::{self, Scanner};
 use argon2::password_hash::{rand_core::OsRng, SaltString};
 use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use bcrypt::{hash, verify, DEFAULT_COST};
@@ -7,8 +9,10 @@ use colored::Colorize;
 use rand::Rng;
 use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
 use rustls_native_certs::load_native_certs;
+use std::fs;
 use std::io::{Read, Write};
 use std::net::TcpStream;
+use std::path::{Path, PathBuf};
 use zxcvbn::zxcvbn;
 
 pub fn ssl_check(domain: &str) {
@@ -219,6 +223,138 @@ pub fn hash_password(password: &str, algorithm: &str) {
     println!();
 }
 
+/// Files larger than this are skipped when scanning for secrets
+const MAX_SCAN_FILE_SIZE: u64 = 1024 * 1024;
+
+pub fn scan_secrets(path: &str, format: Option<&str>, baseline: Option<&str>) -> bool {
+    let format = format.unwrap_or("text");
+    if !["text", "json", "sarif"].contains(&format) {
+        eprintln!(
+            "{} Unknown format '{}' (use text, json or sarif)",
+            "✗".red(),
+            format
+        );
+        return false;
+    }
+
+    let root = Path::new(path);
+    if !root.exists() {
+        eprintln!("{} Path not found: {}", "✗".red(), path);
+        return false;
+    }
+
+    // Inside a git repository, .gitignore'd files are skipped and repo config applies
+    let repo = git2::Repository::discover(root).ok();
+    let config_root = repo
+        .as_ref()
+        .and_then(|r| r.workdir().map(|w| w.to_path_buf()))
+        .or_else(|| Some(root.to_path_buf()));
+
+    let scanner = match SecretsConfig::load(config_root.as_deref())
+        .and_then(|config| Scanner::new(&config))
+        .and_then(|mut scanner| {
+            if let Some(baseline) = baseline {
+                scanner.load_baseline(baseline)?;
+            }
+            Ok(scanner)
+        }) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{} {:#}", "✗".red(), e);
+            return false;
+        }
+    };
+
+    let mut files = Vec::new();
+    collect_scan_files(root, repo.as_ref(), &mut files);
+
+    // Paths are reported relative to the repository root so baselines match
+    // no matter which directory the scan started from
+    let prefix = repo
+        .as_ref()
+        .and_then(|r| r.workdir())
+        .and_then(|w| {
+            fs::canonicalize(root)
+                .ok()?
+                .strip_prefix(fs::canonicalize(w).ok()?)
+                .ok()
+                .map(|p| p.to_path_buf())
+        })
+        .unwrap_or_default();
+
+    let mut findings = Vec::new();
+    for file in files {
+        let relative = prefix.join(file.strip_prefix(root).unwrap_or(&file));
+        let display = if relative.as_os_str().is_empty() {
+            file.to_string_lossy()
+        } else {
+            relative.to_string_lossy()
+        }
+        .replace('\\', "/");
+
+        if scanner.is_path_allowed(&display) {
+            continue;
+        }
+
+        let bytes = match fs::read(&file) {
+            Ok(b) => b,
+            Err(_) => continue,
+        };
+
+        // Binary files are not scanned
+        if bytes.iter().take(8000).any(|b| *b == 0) {
+            continue;
+        }
+
+        findings.extend(scanner.scan_text(&display, &String::from_utf8_lossy(&bytes)));
+    }
+
+    secrets::report(&findings, format, path)
+}
+
+fn collect_scan_files(path: &Path, repo: Option<&git2::Repository>, files: &mut Vec<PathBuf>) {
+    if let Some(repo) = repo {
+        if let Some(relative) = repo.workdir().and_then(|w| {
+            fs::canonicalize(path)
+                .ok()?
+                .strip_prefix(fs::canonicalize(w).ok()?)
+                .ok()
+                .map(|p| p.to_path_buf())
+        }) {
+            if !relative.as_os_str().is_empty()
+                && repo.status_should_ignore(&relative).unwrap_or(false)
+            {
+                return;
+            }
+        }
+    }
+
+    if path.is_dir() {
+        if path.file_name().is_some_and(|n| n == ".git") {
+            return;
+        }
+
+        let mut entries: Vec<PathBuf> = match fs::read_dir(path) {
+            Ok(entries) => entries.flatten().map(|e| e.path()).collect(),
+            Err(_) => return,
+        };
+        entries.sort();
+
+        for entry in entries {
+            // Don't follow symlinks out of the tree
+            if entry.is_symlink() {
+                continue;
+            }
+            collect_scan_files(&entry, repo, files);
+        }
+    } else if fs::metadata(path)
+        .map(|m| m.len() <= MAX_SCAN_FILE_SIZE)
+        .unwrap_or(false)
+    {
+        files.push(path.to_path_buf());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
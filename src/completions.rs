@@ -31,9 +31,9 @@ _profilecore_completions() {{
     # Subcommands (all 97 commands - 100% complete!)
     system_cmds="info uptime processes disk-usage memory cpu load network-stats temperature users service-list service-status"
     network_cmds="public-ip test-port local-ips dns reverse-dns whois trace ping"
-    git_cmds="status log diff branch remote switch-account add-account list-accounts whoami clone pull push stash commit tag rebase"
-    docker_cmds="ps stats logs"
-    security_cmds="ssl-check gen-password check-password hash-password"
+    git_cmds="status log diff branch remote switch-account add-account list-accounts whoami clone pull push stash commit tag rebase prune-branches lint-commits changelog worktree undo stats blame hooks scan-secrets large-files submodule"
+    docker_cmds="ps stats logs start stop restart rm pause unpause kill exec images volumes networks inspect df prune compose events wait-healthy context build"
+    security_cmds="ssl-check gen-password check-password hash-password scan-secrets"
     package_cmds="install list search update upgrade remove info"
     file_cmds="hash size find permissions type"
     env_cmds="list get set"
@@ -188,6 +188,17 @@ _profilecore() {{
         'commit:Create a commit'
         'tag:Create or list tags'
         'rebase:Rebase current branch'
+        'prune-branches:Delete merged or gone branches'
+        'lint-commits:Lint commit messages'
+        'changelog:Generate changelog'
+        'worktree:Manage worktrees'
+        'undo:Undo via reflog'
+        'stats:Show contributor, churn and activity statistics'
+        'blame:Show who last changed each line'
+        'hooks:Manage git hooks from .profilecore/hooks.toml'
+        'scan-secrets:Scan commits or staged changes for secrets'
+        'large-files:Find the largest files in history'
+        'submodule:Inspect and update submodules'
     )
     
     local -a docker_cmds
@@ -195,6 +206,25 @@ _profilecore() {{
         'ps:List containers'
         'stats:Container stats'
         'logs:Container logs'
+        'start:Start containers'
+        'stop:Stop containers'
+        'restart:Restart containers'
+        'rm:Remove containers'
+        'pause:Pause containers'
+        'unpause:Unpause containers'
+        'kill:Kill containers'
+        'exec:Run a command in a container'
+        'images:List images'
+        'volumes:List volumes'
+        'networks:List networks'
+        'inspect:Inspect an object'
+        'df:Show disk usage'
+        'prune:Remove unused objects'
+        'compose:Compose project commands'
+        'events:Stream engine events'
+        'wait-healthy:Wait for a healthy container'
+        'context:List Docker contexts'
+        'build:Build an image'
     )
     
     local -a security_cmds
@@ -203,6 +233,7 @@ _profilecore() {{
         'gen-password:Generate password'
         'check-password:Check password strength'
         'hash-password:Hash password'
+        'scan-secrets:Scan files for committed secrets'
     )
     
     local -a package_cmds
@@ -416,7 +447,7 @@ complete -c profilecore -f -n "__fish_seen_subcommand_from network" -a "whois" -
 complete -c profilecore -f -n "__fish_seen_subcommand_from network" -a "trace" -d "Traceroute"
 complete -c profilecore -f -n "__fish_seen_subcommand_from network" -a "ping" -d "Ping host"
 
-# Git subcommands (all 16 commands)
+# Git subcommands (all 27 commands)
 complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "status" -d "Show git status"
 complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "log" -d "Show git log"
 complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "diff" -d "Show working tree changes"
@@ -433,17 +464,48 @@ complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "stash" -d "S
 complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "commit" -d "Create commit"
 complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "tag" -d "Create or list tags"
 complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "rebase" -d "Rebase branch"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "prune-branches" -d "Delete merged or gone branches"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "lint-commits" -d "Lint commit messages"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "changelog" -d "Generate changelog"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "worktree" -d "Manage worktrees"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "undo" -d "Undo via reflog"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "stats" -d "Show contributor, churn and activity statistics"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "blame" -d "Show who last changed each line"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "hooks" -d "Manage git hooks from .profilecore/hooks.toml"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "scan-secrets" -d "Scan commits or staged changes for secrets"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "large-files" -d "Find the largest files in history"
+complete -c profilecore -f -n "__fish_seen_subcommand_from git" -a "submodule" -d "Inspect and update submodules"
 
-# Docker subcommands (all 3 commands)
+# Docker subcommands (all 22 commands)
 complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "ps" -d "List containers"
 complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "stats" -d "Container stats"
 complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "logs" -d "Container logs"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "start" -d "Start containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "stop" -d "Stop containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "restart" -d "Restart containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "rm" -d "Remove containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "pause" -d "Pause containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "unpause" -d "Unpause containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "kill" -d "Kill containers"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "exec" -d "Run a command in a container"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "images" -d "List images"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "volumes" -d "List volumes"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "networks" -d "List networks"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "inspect" -d "Inspect an object"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "df" -d "Show disk usage"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "prune" -d "Remove unused objects"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "compose" -d "Compose project commands"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "events" -d "Stream engine events"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "wait-healthy" -d "Wait for a healthy container"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "context" -d "List Docker contexts"
+complete -c profilecore -f -n "__fish_seen_subcommand_from docker" -a "build" -d "Build an image"
 
-# Security subcommands (all 4 commands)
+# Security subcommands (all 5 commands)
 complete -c profilecore -f -n "__fish_seen_subcommand_from security" -a "ssl-check" -d "Check SSL certificate"
 complete -c profilecore -f -n "__fish_seen_subcommand_from security" -a "gen-password" -d "Generate password"
 complete -c profilecore -f -n "__fish_seen_subcommand_from security" -a "check-password" -d "Check password strength"
 complete -c profilecore -f -n "__fish_seen_subcommand_from security" -a "hash-password" -d "Hash password"
+complete -c profilecore -f -n "__fish_seen_subcommand_from security" -a "scan-secrets" -d "Scan files for committed secrets"
 
 # Package subcommands (all 7 commands)
 complete -c profilecore -f -n "__fish_seen_subcommand_from package" -a "install" -d "Install package"
@@ -553,9 +615,9 @@ Register-ArgumentCompleter -CommandName profilecore -ScriptBlock {{
     # All 97 commands
     $systemCmds = @('info', 'uptime', 'processes', 'disk-usage', 'memory', 'cpu', 'load', 'network-stats', 'temperature', 'users', 'service-list', 'service-status')
     $networkCmds = @('public-ip', 'test-port', 'local-ips', 'dns', 'reverse-dns', 'whois', 'trace', 'ping')
-    $gitCmds = @('status', 'log', 'diff', 'branch', 'remote', 'switch-account', 'add-account', 'list-accounts', 'whoami', 'clone', 'pull', 'push', 'stash', 'commit', 'tag', 'rebase')
-    $dockerCmds = @('ps', 'stats', 'logs')
-    $securityCmds = @('ssl-check', 'gen-password', 'check-password', 'hash-password')
+    $gitCmds = @('status', 'log', 'diff', 'branch', 'remote', 'switch-account', 'add-account', 'list-accounts', 'whoami', 'clone', 'pull', 'push', 'stash', 'commit', 'tag', 'rebase', 'prune-branches', 'lint-commits', 'changelog', 'worktree', 'undo', 'stats', 'blame', 'hooks', 'scan-secrets', 'large-files', 'submodule')
+    $dockerCmds = @('ps', 'stats', 'logs', 'start', 'stop', 'restart', 'rm', 'pause', 'unpause', 'kill', 'exec', 'images', 'volumes', 'networks', 'inspect', 'df', 'prune', 'compose', 'events', 'wait-healthy', 'context', 'build')
+    $securityCmds = @('ssl-check', 'gen-password', 'check-password', 'hash-password', 'scan-secrets')
     $packageCmds = @('install', 'list', 'search', 'update', 'upgrade', 'remove', 'info')
     $fileCmds = @('hash', 'size', 'find', 'permissions', 'type')
     $envCmds = @('list', 'get', 'set')
@@ -604,3 +666,53 @@ Register-ArgumentCompleter -CommandName profilecore -ScriptBlock {{
 "#
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use gumdrop::Options;
+
+    #[test]
+    fn test_docker_commands_listed_everywhere() {
+        let commands: Vec<&str> = crate::DockerCmd::usage()
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .collect();
+        assert!(commands.contains(&"wait-healthy"));
+
+        let help: Vec<&str> = crate::DOCKER_HELP
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .collect();
+        let source = include_str!("completions.rs");
+        let bash: Vec<&str> = source
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("docker_cmds=\""))
+            .unwrap()
+            .trim_end_matches('"')
+            .split(' ')
+            .collect();
+        let powershell = source
+            .lines()
+            .find(|line| line.trim().starts_with("$dockerCmds"))
+            .unwrap();
+
+        for command in commands {
+            assert!(help.contains(&command), "{} missing from help", command);
+            assert!(bash.contains(&command), "{} missing from bash", command);
+            assert!(
+                source.contains(&format!("'{}:", command)),
+                "{} missing from zsh",
+                command
+            );
+            assert!(
+                source.contains(&format!("docker\" -a \"{}\"", command)),
+                "{} missing from fish",
+                command
+            );
+            assert!(
+                powershell.contains(&format!("'{}'", command)),
+                "{} missing from powershell",
+                command
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Docker settings (endpoint, prune exclusions, etc.) with TOML config
+//!
+//! Read from `<config dir>/profilecore/docker.toml`:
+//!
+//! ```toml
+//! [endpoint]
+//! context = "colima"
+//!
+//! [prune]
+//! keep_labels = ["keep", "env=production"]
+//! ```
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EndpointSettings {
+    /// Engine address (`unix://`, `npipe://`, `tcp://`), used when neither flags nor
+    /// the environment select one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
+    /// Docker context to use instead of the CLI's current context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+
+    /// Directory with `ca.pem`, `cert.pem` and `key.pem` for a TLS `host`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_cert_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PruneSettings {
+    /// Label selectors (`key` or `key=value`); matching resources are never pruned
+    #[serde(default)]
+    pub keep_labels: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DockerSettings {
+    #[serde(default)]
+    pub endpoint: EndpointSettings,
+
+    #[serde(default)]
+    pub prune: PruneSettings,
+}
+
+impl DockerSettings {
+    pub fn load() -> Result<Self> {
+        let path = Self::config_path()?;
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config from {}", path.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config from {}", path.display()))
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().context("Failed to determine config directory")?;
+
+        Ok(config_dir.join("profilecore").join("docker.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_docker_settings() {
+        let settings: DockerSettings = toml::from_str("").unwrap();
+        assert!(settings.prune.keep_labels.is_empty());
+        assert!(settings.endpoint.host.is_none());
+
+        let settings: DockerSettings =
+            toml::from_str("[prune]\nkeep_labels = [\"keep\", \"env=prod\"]").unwrap();
+        assert_eq!(settings.prune.keep_labels, vec!["keep", "env=prod"]);
+
+        let settings: DockerSettings =
+            toml::from_str("[endpoint]\nhost = \"tcp://build:2376\"\ntls_cert_path = \"~/certs\"")
+                .unwrap();
+        assert_eq!(settings.endpoint.host.as_deref(), Some("tcp://build:2376"));
+        assert_eq!(settings.endpoint.tls_cert_path.as_deref(), Some("~/certs"));
+    }
+}
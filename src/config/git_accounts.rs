@@ -11,6 +11,9 @@ pub struct GitAccount {
     pub email: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signing_key: Option<String>,
+    /// Remote patterns (`host/owner/repo`, `*` wildcard) that select this account on clone
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub url_patterns: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -69,6 +72,15 @@ impl GitAccountsConfig {
         self.accounts.iter().find(|a| a.name == name)
     }
 
+    /// First account with a URL pattern matching `host/path` (e.g. "github.com/acme/api")
+    pub fn account_for_remote(&self, remote: &str) -> Option<&GitAccount> {
+        self.accounts.iter().find(|a| {
+            a.url_patterns
+                .iter()
+                .any(|p| crate::config::matches_pattern(p, remote))
+        })
+    }
+
     #[allow(dead_code)]
     pub fn remove_account(&mut self, name: &str) -> Result<()> {
         let original_len = self.accounts.len();
@@ -88,3 +100,29 @@ impl GitAccountsConfig {
         Ok(config_dir.join("profilecore").join("git-accounts.toml"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_for_remote() {
+        let account = |name: &str, patterns: &[&str]| GitAccount {
+            name: name.to_string(),
+            email: format!("{}@example.com", name),
+            signing_key: None,
+            url_patterns: patterns.iter().map(|p| p.to_string()).collect(),
+        };
+        let config = GitAccountsConfig {
+            accounts: vec![
+                account("personal", &[]),
+                account("work", &["github.com/acme/*", "gitlab.acme.dev/*"]),
+            ],
+        };
+
+        let found = |remote: &str| config.account_for_remote(remote).map(|a| a.name.as_str());
+        assert_eq!(found("github.com/acme/api"), Some("work"));
+        assert_eq!(found("gitlab.acme.dev/platform/infra/tools"), Some("work"));
+        assert_eq!(found("github.com/someone/api"), None);
+    }
+}
@@ -0,0 +1,292 @@
+//! Git behaviour settings (protected branches, commit rules, etc.) with TOML config
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchSettings {
+    /// Branch name patterns (`*` wildcard) that are never pruned
+    #[serde(default = "default_protected_branches")]
+    pub protected: Vec<String>,
+
+    /// Override for the default branch (detected from origin/HEAD otherwise)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+}
+
+impl Default for BranchSettings {
+    fn default() -> Self {
+        Self {
+            protected: default_protected_branches(),
+            default_branch: None,
+        }
+    }
+}
+
+fn default_protected_branches() -> Vec<String> {
+    vec![
+        "main".to_string(),
+        "master".to_string(),
+        "develop".to_string(),
+        "release/*".to_string(),
+    ]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitSettings {
+    /// Validate messages on every `git commit` (otherwise only with --lint)
+    #[serde(default)]
+    pub lint: bool,
+
+    /// Allowed conventional commit types
+    #[serde(default = "default_commit_types")]
+    pub types: Vec<String>,
+
+    /// Allowed scopes (empty allows any scope)
+    #[serde(default)]
+    pub scopes: Vec<String>,
+
+    #[serde(default)]
+    pub require_scope: bool,
+
+    /// Maximum length of the header line
+    #[serde(default = "default_max_subject_length")]
+    pub max_subject_length: usize,
+
+    /// Require a `BREAKING CHANGE:` footer when the header is marked with `!`
+    #[serde(default)]
+    pub require_breaking_footer: bool,
+}
+
+impl Default for CommitSettings {
+    fn default() -> Self {
+        Self {
+            lint: false,
+            types: default_commit_types(),
+            scopes: Vec::new(),
+            require_scope: false,
+            max_subject_length: default_max_subject_length(),
+            require_breaking_footer: false,
+        }
+    }
+}
+
+fn default_commit_types() -> Vec<String> {
+    [
+        "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore",
+        "revert",
+    ]
+    .iter()
+    .map(|t| t.to_string())
+    .collect()
+}
+
+fn default_max_subject_length() -> usize {
+    72
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseSettings {
+    /// Branch name patterns that release tags may be created from
+    #[serde(default = "default_release_branches")]
+    pub branches: Vec<String>,
+
+    /// Prefix for version tags (e.g. "v" for v1.2.3)
+    #[serde(default = "default_tag_prefix")]
+    pub tag_prefix: String,
+
+    /// Identifier used for pre-release versions (e.g. "rc" for 1.2.3-rc.1)
+    #[serde(default = "default_pre_release_id")]
+    pub pre_release_id: String,
+}
+
+impl Default for ReleaseSettings {
+    fn default() -> Self {
+        Self {
+            branches: default_release_branches(),
+            tag_prefix: default_tag_prefix(),
+            pre_release_id: default_pre_release_id(),
+        }
+    }
+}
+
+fn default_release_branches() -> Vec<String> {
+    vec![
+        "main".to_string(),
+        "master".to_string(),
+        "release/*".to_string(),
+    ]
+}
+
+fn default_tag_prefix() -> String {
+    "v".to_string()
+}
+
+fn default_pre_release_id() -> String {
+    "rc".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneSettings {
+    /// Base directory for clones without an explicit path (e.g. "~/src")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+
+    /// Path below `root`, with {host}, {owner} and {repo} placeholders
+    #[serde(default = "default_clone_layout")]
+    pub layout: String,
+}
+
+impl Default for CloneSettings {
+    fn default() -> Self {
+        Self {
+            root: None,
+            layout: default_clone_layout(),
+        }
+    }
+}
+
+impl CloneSettings {
+    /// Where a repository at `host` / `path` (e.g. "owner/repo") should be cloned
+    pub fn target_dir(&self, host: &str, path: &str) -> Option<PathBuf> {
+        let root = self.root.as_ref()?;
+        let root = match root.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir()?.join(rest),
+            None => PathBuf::from(root),
+        };
+
+        // Nested groups (GitLab) all belong to the owner part
+        let (owner, repo) = path.rsplit_once('/').unwrap_or(("", path));
+        let relative = self
+            .layout
+            .replace("{host}", host)
+            .replace("{owner}", owner)
+            .replace("{repo}", repo);
+
+        Some(
+            relative
+                .split('/')
+                .filter(|part| !part.is_empty())
+                .fold(root, |dir, part| dir.join(part)),
+        )
+    }
+}
+
+fn default_clone_layout() -> String {
+    "{host}/{owner}/{repo}".to_string()
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GitSettings {
+    #[serde(default)]
+    pub branches: BranchSettings,
+
+    #[serde(default)]
+    pub commit: CommitSettings,
+
+    #[serde(default)]
+    pub release: ReleaseSettings,
+
+    #[serde(default)]
+    pub clone: CloneSettings,
+}
+
+impl GitSettings {
+    pub fn load() -> Result<Self> {
+        let path = Self::config_path()?;
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config from {}", path.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config from {}", path.display()))
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().context("Failed to determine config directory")?;
+
+        Ok(config_dir.join("profilecore").join("git.toml"))
+    }
+}
+
+/// Match a name against a simple wildcard pattern (`*` matches any run of characters)
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == name;
+    }
+
+    let mut rest = name;
+    for (i, part) in parts.iter().enumerate() {
+        if i == 0 {
+            match rest.strip_prefix(part) {
+                Some(r) => rest = r,
+                None => return false,
+            }
+        } else if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        } else {
+            match rest.find(part) {
+                Some(idx) => rest = &rest[idx + part.len()..],
+                None => return false,
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("main", "main"));
+        assert!(!matches_pattern("main", "maintenance"));
+        assert!(matches_pattern("release/*", "release/1.2"));
+        assert!(!matches_pattern("release/*", "feature/release"));
+        assert!(matches_pattern("*-wip", "login-wip"));
+        assert!(matches_pattern("hotfix/*/urgent", "hotfix/123/urgent"));
+        assert!(!matches_pattern("hotfix/*/urgent", "hotfix/123/later"));
+    }
+
+    #[test]
+    fn test_default_settings() {
+        let settings: GitSettings = toml::from_str("").unwrap();
+        assert!(settings.branches.protected.contains(&"main".to_string()));
+        assert!(settings.branches.default_branch.is_none());
+        assert!(!settings.commit.lint);
+        assert_eq!(settings.commit.max_subject_length, 72);
+        assert_eq!(settings.release.tag_prefix, "v");
+        assert!(settings.clone.root.is_none());
+    }
+
+    #[test]
+    fn test_clone_target_dir() {
+        let mut clone = CloneSettings::default();
+        assert!(clone.target_dir("github.com", "acme/api").is_none());
+
+        clone.root = Some("/src".to_string());
+        assert_eq!(
+            clone.target_dir("github.com", "acme/api"),
+            Some(PathBuf::from("/src/github.com/acme/api"))
+        );
+        assert_eq!(
+            clone.target_dir("gitlab.com", "group/sub/tool"),
+            Some(PathBuf::from("/src/gitlab.com/group/sub/tool"))
+        );
+
+        clone.layout = "{owner}-{repo}".to_string();
+        assert_eq!(
+            clone.target_dir("github.com", "acme/api"),
+            Some(PathBuf::from("/src/acme-api"))
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Repository git hooks defined in a versioned `.profilecore/hooks.toml`
+//!
+//! ```toml
+//! [pre-commit]
+//! parallel = true
+//!
+//! [[pre-commit.commands]]
+//! name = "rustfmt"
+//! run = "rustfmt --check {files}"
+//! glob = ["*.rs"]
+//!
+//! [[pre-commit.commands]]
+//! builtin = "scan-secrets"
+//!
+//! [[commit-msg.commands]]
+//! builtin = "lint-commit-msg"
+//! ```
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Hooks that git runs client-side and that may be configured
+pub const SUPPORTED_HOOKS: &[&str] = &[
+    "applypatch-msg",
+    "pre-applypatch",
+    "post-applypatch",
+    "pre-commit",
+    "pre-merge-commit",
+    "prepare-commit-msg",
+    "commit-msg",
+    "post-commit",
+    "pre-rebase",
+    "post-checkout",
+    "post-merge",
+    "pre-push",
+    "post-rewrite",
+];
+
+/// Actions implemented by ProfileCore itself
+pub const BUILTIN_ACTIONS: &[&str] = &["lint-commit-msg", "scan-secrets"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HookConfig {
+    /// Run this hook's commands concurrently
+    #[serde(default)]
+    pub parallel: bool,
+
+    #[serde(default)]
+    pub commands: Vec<HookCommand>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HookCommand {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Shell command; `{files}` expands to the matching staged files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<String>,
+
+    /// Built-in action to run instead of a shell command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub builtin: Option<String>,
+
+    /// Only run when a staged file matches one of these patterns
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub glob: Vec<String>,
+}
+
+impl HookCommand {
+    /// Label used in listings and reports
+    pub fn label(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| self.builtin.clone())
+            .or_else(|| self.run.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HooksConfig {
+    #[serde(flatten)]
+    pub hooks: BTreeMap<String, HookConfig>,
+}
+
+impl HooksConfig {
+    /// Load hooks from `<repo root>/.profilecore/hooks.toml`, or `None` if the file is missing
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = Self::config_path(root);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read hooks from {}", path.display()))?;
+
+        let config = Self::parse(&contents)
+            .with_context(|| format!("Failed to parse hooks from {}", path.display()))?;
+
+        Ok(Some(config))
+    }
+
+    pub fn config_path(root: &Path) -> PathBuf {
+        root.join(".profilecore").join("hooks.toml")
+    }
+
+    fn parse(contents: &str) -> Result<Self> {
+        let config: Self = toml::from_str(contents)?;
+
+        for (hook, hook_config) in &config.hooks {
+            if !SUPPORTED_HOOKS.contains(&hook.as_str()) {
+                bail!("unknown hook '{}'", hook);
+            }
+
+            for command in &hook_config.commands {
+                match (&command.run, &command.builtin) {
+                    (Some(_), None) => {}
+                    (None, Some(builtin)) if BUILTIN_ACTIONS.contains(&builtin.as_str()) => {}
+                    (None, Some(builtin)) => {
+                        bail!("{}: unknown builtin '{}'", hook, builtin)
+                    }
+                    _ => bail!(
+                        "{}: each command needs exactly one of 'run' or 'builtin'",
+                        hook
+                    ),
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hooks() {
+        let config = HooksConfig::parse(
+            r#"
+[pre-commit]
+parallel = true
+
+[[pre-commit.commands]]
+name = "fmt"
+run = "cargo fmt --check"
+glob = ["*.rs"]
+
+[[commit-msg.commands]]
+builtin = "lint-commit-msg"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.hooks.len(), 2);
+        assert!(config.hooks["pre-commit"].parallel);
+        assert_eq!(config.hooks["pre-commit"].commands[0].glob, vec!["*.rs"]);
+        assert_eq!(
+            config.hooks["commit-msg"].commands[0].label(),
+            "lint-commit-msg"
+        );
+    }
+
+    #[test]
+    fn test_parse_hooks_rejects_invalid() {
+        assert!(HooksConfig::parse("[[pre-comit.commands]]\nrun = \"true\"").is_err());
+        assert!(HooksConfig::parse("[[pre-commit.commands]]\nname = \"empty\"").is_err());
+        assert!(HooksConfig::parse("[[pre-commit.commands]]\nbuiltin = \"nope\"").is_err());
+        assert!(HooksConfig::parse(
+            "[[pre-commit.commands]]\nrun = \"true\"\nbuiltin = \"lint-commit-msg\""
+        )
+        .is_err());
+    }
+}
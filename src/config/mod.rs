@@ -1,3 +1,11 @@
+pub mod docker;
 pub mod git_accounts;
+pub mod git_settings;
+pub mod hooks;
+pub mod secrets;
 
+pub use docker::*;
 pub use git_accounts::*;
+pub use git_settings::*;
+pub use hooks::*;
+pub use secrets::*;
@@ -0,0 +1,87 @@
+//! Secret scanning rules and allowlists with TOML config
+//!
+//! Read from `.profilecore/secrets.toml` in the scanned repository, falling back to
+//! `<config dir>/profilecore/secrets.toml`:
+//!
+//! ```toml
+//! [[rules]]
+//! id = "internal-token"
+//! description = "Internal API token"
+//! regex = "itk_[A-Za-z0-9]{32}"
+//!
+//! [allowlist]
+//! paths = ["tests/fixtures/*"]
+//! regexes = ["EXAMPLE"]
+//! ```
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretRuleConfig {
+    pub id: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    /// Pattern to match; capture group 1 (if present) is the secret itself
+    pub regex: String,
+
+    /// Minimum Shannon entropy of the secret for a match to count
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entropy: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecretAllowlist {
+    /// Path patterns (`*` wildcard) that are never scanned
+    #[serde(default)]
+    pub paths: Vec<String>,
+
+    /// Findings whose secret matches one of these patterns are ignored
+    #[serde(default)]
+    pub regexes: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SecretsConfig {
+    /// Rules added to the built-in set
+    #[serde(default)]
+    pub rules: Vec<SecretRuleConfig>,
+
+    #[serde(default)]
+    pub allowlist: SecretAllowlist,
+
+    /// Skip the generic high-entropy string rule
+    #[serde(default)]
+    pub disable_entropy: bool,
+}
+
+impl SecretsConfig {
+    /// Load the repository config under `root` if present, else the user config
+    pub fn load(root: Option<&Path>) -> Result<Self> {
+        let repo_path = root.map(|r| r.join(".profilecore").join("secrets.toml"));
+        let path = match repo_path {
+            Some(path) if path.exists() => path,
+            _ => Self::config_path()?,
+        };
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config from {}", path.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config from {}", path.display()))
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().context("Failed to determine config directory")?;
+
+        Ok(config_dir.join("profilecore").join("secrets.toml"))
+    }
+}
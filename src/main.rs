@@ -372,6 +372,9 @@ enum GitCmd {
     #[options(help = "list branches")]
     Branch(BranchOpts),
 
+    #[options(help = "delete merged or gone local branches")]
+    PruneBranches(PruneBranchesOpts),
+
     #[options(help = "list remote repositories")]
     Remote(RemoteOpts),
 
@@ -402,11 +405,41 @@ enum GitCmd {
     #[options(help = "create a commit")]
     Commit(CommitOpts),
 
+    #[options(help = "check commit messages in a range against conventional commit rules")]
+    LintCommits(LintCommitsOpts),
+
+    #[options(help = "generate a changelog from commit history")]
+    Changelog(ChangelogOpts),
+
     #[options(help = "create or list tags")]
     Tag(TagOpts),
 
     #[options(help = "rebase current branch")]
     Rebase(RebaseOpts),
+
+    #[options(help = "manage worktrees")]
+    Worktree(WorktreeOpts),
+
+    #[options(help = "restore HEAD to an earlier state from the reflog")]
+    Undo(UndoOpts),
+
+    #[options(help = "show contributor, churn and activity statistics")]
+    Stats(GitStatsOpts),
+
+    #[options(help = "show who last changed each line of a file")]
+    Blame(BlameOpts),
+
+    #[options(help = "manage git hooks from .profilecore/hooks.toml")]
+    Hooks(HooksOpts),
+
+    #[options(help = "scan commits or staged changes for secrets")]
+    ScanSecrets(GitScanSecretsOpts),
+
+    #[options(help = "find the largest files in history")]
+    LargeFiles(LargeFilesOpts),
+
+    #[options(help = "inspect and update submodules")]
+    Submodule(SubmoduleOpts),
 }
 
 #[derive(Options)]
@@ -437,6 +470,33 @@ struct BranchOpts {
 
     #[options(help = "list all branches", short = "a")]
     all: bool,
+
+    #[options(help = "report branches merged into the default branch")]
+    merged: bool,
+
+    #[options(help = "report branches whose upstream is gone")]
+    gone: bool,
+
+    #[options(help = "report branches with no commits in DAYS days", meta = "DAYS")]
+    stale: Option<u64>,
+}
+
+#[derive(Options)]
+struct PruneBranchesOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(help = "preview branches that would be deleted")]
+    dry_run: bool,
+
+    #[options(help = "delete merged candidates without prompting")]
+    yes: bool,
+
+    #[options(
+        no_short,
+        help = "also delete unmerged branches whose upstream is gone"
+    )]
+    include_unmerged: bool,
 }
 
 #[derive(Options)]
@@ -467,6 +527,13 @@ struct AddAccountOpts {
 
     #[options(help = "GPG/SSH signing key", meta = "KEY")]
     signing_key: Option<String>,
+
+    #[options(
+        help = "remote pattern that selects this account on clone, e.g. github.com/acme/* (repeatable)",
+        meta = "PATTERN",
+        short = "u"
+    )]
+    url_pattern: Vec<String>,
 }
 
 #[derive(Options)]
@@ -488,6 +555,21 @@ struct CloneOpts {
 
     #[options(free, help = "repository URL and optional path")]
     args: Vec<String>,
+
+    #[options(help = "create a shallow clone with this many commits", meta = "N")]
+    depth: Option<i32>,
+
+    #[options(
+        help = "check out this branch instead of the remote HEAD",
+        meta = "BRANCH"
+    )]
+    branch: Option<String>,
+
+    #[options(help = "initialize and update submodules", no_short)]
+    recurse_submodules: bool,
+
+    #[options(help = "git account to use (default: matched by URL)", meta = "NAME")]
+    account: Option<String>,
 }
 
 #[derive(Options)]
@@ -513,8 +595,23 @@ struct StashOpts {
     #[options(help = "show help")]
     help: bool,
 
-    #[options(free, help = "stash action: save, pop, list, clear")]
+    #[options(
+        free,
+        help = "action: save, list, show, apply, pop, drop, branch, clear"
+    )]
     action: Option<String>,
+
+    #[options(free, help = "stash index (N or stash@{N}); branch name for 'branch'")]
+    args: Vec<String>,
+
+    #[options(help = "stash message (for save)", short = "m", meta = "MSG")]
+    message: Option<String>,
+
+    #[options(help = "include untracked files (for save)", short = "u")]
+    include_untracked: bool,
+
+    #[options(help = "keep staged changes in the index (for save)", short = "k")]
+    keep_index: bool,
 }
 
 #[derive(Options)]
@@ -527,6 +624,48 @@ struct CommitOpts {
 
     #[options(help = "stage all changes", short = "a")]
     all: bool,
+
+    #[options(help = "validate the message against conventional commit rules")]
+    lint: bool,
+
+    #[options(help = "skip message validation even if enabled in config")]
+    no_lint: bool,
+
+    #[options(help = "build the message interactively", short = "i")]
+    interactive: bool,
+}
+
+#[derive(Options)]
+struct LintCommitsOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "revision range (default: <default-branch>..HEAD)")]
+    range: Option<String>,
+}
+
+#[derive(Options)]
+struct ChangelogOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(
+        free,
+        help = "revision range <from>..<to> (default: <latest-tag>..HEAD)"
+    )]
+    range: Option<String>,
+
+    #[options(help = "output format: markdown, json", meta = "FORMAT")]
+    format: Option<String>,
+
+    #[options(
+        help = "version heading (default: <to> or Unreleased)",
+        meta = "VERSION"
+    )]
+    version: Option<String>,
+
+    #[options(help = "insert the section into a changelog file", meta = "FILE")]
+    prepend: Option<String>,
 }
 
 #[derive(Options)]
@@ -542,6 +681,197 @@ struct TagOpts {
 
     #[options(help = "list all tags", short = "l")]
     list: bool,
+
+    #[options(
+        help = "create the next version tag: major, minor, patch, pre, auto",
+        short = "b",
+        meta = "LEVEL"
+    )]
+    bump: Option<String>,
+
+    #[options(help = "infer the bump level from conventional commits")]
+    infer: bool,
+
+    #[options(help = "create a signed tag", short = "s")]
+    sign: bool,
+
+    #[options(help = "show the next version without creating the tag")]
+    dry_run: bool,
+
+    #[options(help = "create the tag even with no commits since the latest one")]
+    force: bool,
+}
+
+#[derive(Options)]
+struct WorktreeOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "action: list, add, remove, prune")]
+    action: Option<String>,
+
+    #[options(free, help = "branch (and optional path) for add; name for remove")]
+    args: Vec<String>,
+
+    #[options(help = "create the branch from HEAD (for add)", short = "b")]
+    new_branch: bool,
+
+    #[options(help = "remove even with uncommitted changes or a lock", short = "f")]
+    force: bool,
+}
+
+#[derive(Options)]
+struct UndoOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(help = "number of reflog entries to show", default = "10", meta = "N")]
+    limit: usize,
+
+    #[options(help = "restore HEAD@{N} without prompting", meta = "N")]
+    to: Option<usize>,
+
+    #[options(help = "reset mode: soft, mixed, hard", meta = "MODE")]
+    mode: Option<String>,
+
+    #[options(help = "skip the hard reset confirmation")]
+    yes: bool,
+}
+
+#[derive(Options)]
+struct GitStatsOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(help = "rows per table", default = "10", meta = "N")]
+    top: usize,
+
+    #[options(help = "output format: text, json", meta = "FORMAT")]
+    format: Option<String>,
+
+    #[options(
+        help = "timeline bucket: week, month, year",
+        default = "month",
+        meta = "PERIOD"
+    )]
+    period: String,
+
+    #[options(help = "directory depth for bus factor", default = "1", meta = "N")]
+    depth: usize,
+
+    #[options(help = "ignore and rebuild the stats cache", no_short)]
+    no_cache: bool,
+}
+
+#[derive(Options)]
+struct BlameOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "file to blame")]
+    file: String,
+
+    #[options(
+        short = "L",
+        help = "line range: START,END or START,+COUNT",
+        meta = "RANGE"
+    )]
+    lines: Option<String>,
+
+    #[options(help = "blame the file as of this revision", meta = "REV")]
+    rev: Option<String>,
+
+    #[options(short = "w", help = "ignore whitespace changes")]
+    ignore_whitespace: bool,
+
+    #[options(
+        help = "revisions to skip (default: .git-blame-ignore-revs)",
+        meta = "FILE",
+        no_short
+    )]
+    ignore_revs_file: Option<String>,
+
+    #[options(help = "do not skip any revisions", no_short)]
+    no_ignore_revs: bool,
+
+    #[options(help = "machine-readable output like git blame --porcelain")]
+    porcelain: bool,
+
+    #[options(help = "output format: text, json", meta = "FORMAT")]
+    format: Option<String>,
+}
+
+#[derive(Options)]
+struct HooksOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "action: install, list, run, uninstall")]
+    action: Option<String>,
+
+    #[options(free, help = "hook names (for run: hook name then hook arguments)")]
+    args: Vec<String>,
+
+    #[options(
+        help = "replace existing hooks not managed by profilecore",
+        short = "f"
+    )]
+    force: bool,
+}
+
+#[derive(Options)]
+struct GitScanSecretsOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "revision range <from>..<to> (default: all of HEAD)")]
+    range: Option<String>,
+
+    #[options(help = "scan staged changes instead of commits (for pre-commit)")]
+    staged: bool,
+
+    #[options(help = "output format: text, json, sarif", meta = "FORMAT")]
+    format: Option<String>,
+
+    #[options(help = "ignore findings from a previous JSON report", meta = "FILE")]
+    baseline: Option<String>,
+}
+
+#[derive(Options)]
+struct LargeFilesOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(help = "number of files to show", default = "20", meta = "N")]
+    top: usize,
+
+    #[options(
+        help = "minimum blob size (e.g. 100K, 5M)",
+        default = "0",
+        meta = "SIZE"
+    )]
+    min_size: String,
+}
+
+#[derive(Options)]
+struct SubmoduleOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "action: status, init, update, sync, foreach")]
+    action: Option<String>,
+
+    #[options(free, help = "submodule paths (for foreach: the command to run)")]
+    args: Vec<String>,
+
+    #[options(help = "initialize submodules before updating")]
+    init: bool,
+
+    #[options(help = "also process nested submodules")]
+    recursive: bool,
+
+    #[options(help = "parallel updates", default = "4", meta = "N")]
+    jobs: usize,
 }
 
 #[derive(Options)]
@@ -552,8 +882,23 @@ struct RebaseOpts {
     #[options(free, help = "branch to rebase onto")]
     branch: String,
 
-    #[options(help = "interactive rebase", short = "i")]
+    #[options(
+        help = "choose pick/reword/squash/fixup/drop and order interactively",
+        short = "i"
+    )]
     interactive: bool,
+
+    #[options(help = "run a rebase plan file (git todo format)", meta = "FILE")]
+    plan: Option<String>,
+
+    #[options(help = "print the default rebase plan", no_short)]
+    print_plan: bool,
+
+    #[options(help = "continue after resolving conflicts", long = "continue")]
+    cont: bool,
+
+    #[options(help = "abort and restore the original branch")]
+    abort: bool,
 }
 
 #[derive(Options)]
@@ -561,10 +906,27 @@ struct DockerOpts {
     #[options(help = "show help for docker")]
     help: bool,
 
+    #[options(
+        short = "H",
+        help = "engine address (unix://, npipe://, tcp://)",
+        meta = "HOST"
+    )]
+    host: Option<String>,
+
+    #[options(no_short, help = "Docker context to use", meta = "NAME")]
+    context: Option<String>,
+
     #[options(command)]
     command: Option<DockerCmd>,
 }
 
+/// `docker --help` text; every `DockerCmd` must be listed
+const DOCKER_HELP: &str = "\
+Usage: profilecore docker [--host HOST | --context NAME] <command>
+Commands: ps, stats, logs, start, stop, restart, rm, pause, unpause, kill, exec,
+          images, volumes, networks, inspect, df, prune, compose, events,
+          wait-healthy, context, build";
+
 #[derive(Options)]
 enum DockerCmd {
     #[options(help = "list docker containers")]
@@ -575,6 +937,63 @@ enum DockerCmd {
 
     #[options(help = "show container logs")]
     Logs(LogsOpts),
+
+    #[options(help = "start containers")]
+    Start(ContainerActionOpts),
+
+    #[options(help = "stop containers")]
+    Stop(ContainerActionOpts),
+
+    #[options(help = "restart containers")]
+    Restart(ContainerActionOpts),
+
+    #[options(help = "remove containers")]
+    Rm(ContainerActionOpts),
+
+    #[options(help = "pause containers")]
+    Pause(ContainerActionOpts),
+
+    #[options(help = "unpause containers")]
+    Unpause(ContainerActionOpts),
+
+    #[options(help = "kill containers")]
+    Kill(ContainerActionOpts),
+
+    #[options(help = "run a command in a running container")]
+    Exec(DockerExecOpts),
+
+    #[options(help = "list images")]
+    Images(ImagesOpts),
+
+    #[options(help = "list volumes")]
+    Volumes(DockerListOpts),
+
+    #[options(help = "list networks")]
+    Networks(DockerListOpts),
+
+    #[options(help = "show details of a container, image, volume or network")]
+    Inspect(InspectOpts),
+
+    #[options(help = "show docker disk usage")]
+    Df(DockerListOpts),
+
+    #[options(help = "remove unused containers, images, volumes and build cache")]
+    Prune(PruneOpts),
+
+    #[options(help = "compose project in the current directory")]
+    Compose(ComposeOpts),
+
+    #[options(help = "stream engine events")]
+    Events(EventsOpts),
+
+    #[options(help = "wait until a container's healthcheck passes")]
+    WaitHealthy(WaitHealthyOpts),
+
+    #[options(help = "list Docker contexts and the endpoint in use")]
+    Context(DockerListOpts),
+
+    #[options(help = "build an image from a directory")]
+    Build(DockerBuildOpts),
 }
 
 #[derive(Options)]
@@ -588,8 +1007,25 @@ struct StatsOpts {
     #[options(help = "show help")]
     help: bool,
 
-    #[options(free, help = "container name or ID")]
-    container: String,
+    #[options(free, help = "container names or IDs (default: all running)")]
+    containers: Vec<String>,
+
+    #[options(help = "print a single snapshot instead of a live view")]
+    no_stream: bool,
+
+    #[options(
+        help = "output format: table, json",
+        default = "table",
+        meta = "FORMAT"
+    )]
+    format: String,
+
+    #[options(
+        help = "sort by: cpu, mem, net, block, pids, name",
+        default = "cpu",
+        meta = "KEY"
+    )]
+    sort: String,
 }
 
 #[derive(Options)]
@@ -597,11 +1033,333 @@ struct LogsOpts {
     #[options(help = "show help")]
     help: bool,
 
-    #[options(free, help = "container name or ID")]
-    container: String,
+    #[options(free, help = "container names or IDs")]
+    containers: Vec<String>,
+
+    #[options(
+        help = "number of lines to show (0 for all)",
+        default = "50",
+        meta = "N"
+    )]
+    lines: usize,
+
+    #[options(help = "follow log output until Ctrl-C")]
+    follow: bool,
+
+    #[options(
+        help = "show logs since a time (e.g. 10m, 2h, 2024-01-31)",
+        meta = "TIME"
+    )]
+    since: Option<String>,
+
+    #[options(help = "show logs until a time", meta = "TIME")]
+    until: Option<String>,
+
+    #[options(help = "show timestamps")]
+    timestamps: bool,
+
+    #[options(help = "only show lines matching a regex", meta = "REGEX")]
+    grep: Option<String>,
+
+    #[options(no_short, help = "highlight matches of a regex", meta = "REGEX")]
+    highlight: Option<String>,
+
+    #[options(no_short, help = "only show stdout")]
+    stdout: bool,
+
+    #[options(no_short, help = "only show stderr")]
+    stderr: bool,
+}
+
+#[derive(Options)]
+struct ContainerActionOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "container names or ID prefixes")]
+    containers: Vec<String>,
+
+    #[options(help = "select containers by label (key or key=value)", meta = "LABEL")]
+    label: Vec<String>,
+
+    #[options(help = "seconds to wait before killing (stop, restart)", meta = "SECS")]
+    time: Option<i64>,
+
+    #[options(help = "signal to send (kill)", meta = "SIGNAL")]
+    signal: Option<String>,
+
+    #[options(help = "force removal of running containers (rm)")]
+    force: bool,
+
+    #[options(help = "remove anonymous volumes (rm)")]
+    volumes: bool,
+
+    #[options(help = "skip confirmation")]
+    yes: bool,
+}
+
+#[derive(Options)]
+struct DockerExecOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "container, then the command (after --); defaults to sh")]
+    args: Vec<String>,
+
+    #[options(help = "keep stdin open and forward it")]
+    interactive: bool,
+
+    #[options(help = "allocate a pseudo-TTY")]
+    tty: bool,
+
+    #[options(help = "user to run as", meta = "USER")]
+    user: Option<String>,
+
+    #[options(help = "working directory inside the container", meta = "DIR")]
+    workdir: Option<String>,
+
+    #[options(help = "set environment variables", meta = "KEY=VALUE")]
+    env: Vec<String>,
+}
+
+#[derive(Options)]
+struct ImagesOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(help = "include intermediate images")]
+    all: bool,
+
+    #[options(help = "only show untagged images")]
+    dangling: bool,
+}
+
+#[derive(Options)]
+struct DockerListOpts {
+    #[options(help = "show help")]
+    help: bool,
+}
+
+#[derive(Options)]
+struct InspectOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "name or ID")]
+    target: Option<String>,
+
+    #[options(
+        no_short,
+        long = "type",
+        help = "only look for this kind: container, image, volume, network",
+        meta = "TYPE"
+    )]
+    kind: Option<String>,
+
+    #[options(help = "print the full JSON")]
+    json: bool,
+}
+
+#[derive(Options)]
+struct PruneOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(help = "remove dangling images")]
+    images: bool,
+
+    #[options(help = "remove stopped containers")]
+    containers: bool,
+
+    #[options(help = "remove unused anonymous volumes")]
+    volumes: bool,
+
+    #[options(help = "with --volumes, also remove named volumes", no_short)]
+    all_volumes: bool,
+
+    #[options(help = "remove build cache")]
+    build_cache: bool,
+
+    #[options(
+        help = "only remove objects older than this (e.g. 24h, 7d)",
+        meta = "AGE"
+    )]
+    older_than: Option<String>,
+
+    #[options(help = "show what would be removed")]
+    dry_run: bool,
+
+    #[options(help = "skip confirmation")]
+    yes: bool,
+}
+
+#[derive(Options)]
+struct ComposeOpts {
+    #[options(help = "show help for compose")]
+    help: bool,
+
+    #[options(
+        help = "compose file (default: compose.yaml, docker-compose.yml, ...)",
+        meta = "FILE"
+    )]
+    file: Option<String>,
+
+    #[options(
+        help = "project name (default: from the file or directory)",
+        meta = "NAME"
+    )]
+    project_name: Option<String>,
+
+    #[options(command)]
+    command: Option<ComposeCmd>,
+}
+
+#[derive(Options)]
+enum ComposeCmd {
+    #[options(help = "show services and their containers")]
+    Ps(DockerListOpts),
+
+    #[options(help = "show service logs")]
+    Logs(ComposeLogsOpts),
+
+    #[options(help = "restart services")]
+    Restart(ComposeRestartOpts),
+}
+
+#[derive(Options)]
+struct ComposeLogsOpts {
+    #[options(help = "show help")]
+    help: bool,
 
-    #[options(help = "number of lines to show", default = "50", meta = "N")]
+    #[options(free, help = "services (default: all)")]
+    services: Vec<String>,
+
+    #[options(
+        help = "number of lines to show (0 for all)",
+        default = "50",
+        meta = "N"
+    )]
     lines: usize,
+
+    #[options(help = "follow log output until Ctrl-C")]
+    follow: bool,
+
+    #[options(
+        help = "show logs since a time (e.g. 10m, 2h, 2024-01-31)",
+        meta = "TIME"
+    )]
+    since: Option<String>,
+
+    #[options(help = "show timestamps")]
+    timestamps: bool,
+
+    #[options(help = "only show lines matching a regex", meta = "REGEX")]
+    grep: Option<String>,
+}
+
+#[derive(Options)]
+struct ComposeRestartOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "services (default: all)")]
+    services: Vec<String>,
+
+    #[options(help = "seconds to wait before killing", meta = "SECS")]
+    time: Option<i64>,
+}
+
+#[derive(Options)]
+struct EventsOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(
+        long = "type",
+        short = "t",
+        help = "object type (container, image, volume, network, ...)",
+        meta = "TYPE"
+    )]
+    kind: Vec<String>,
+
+    #[options(help = "container name or ID", meta = "NAME")]
+    container: Vec<String>,
+
+    #[options(help = "image name or ID", meta = "NAME")]
+    image: Vec<String>,
+
+    #[options(help = "label (key or key=value)", meta = "LABEL")]
+    label: Vec<String>,
+
+    #[options(help = "event name (start, die, health_status, ...)", meta = "EVENT")]
+    event: Vec<String>,
+
+    #[options(
+        help = "replay events since a time (e.g. 10m, 2024-01-31)",
+        meta = "TIME"
+    )]
+    since: Option<String>,
+
+    #[options(help = "stop at a time", meta = "TIME")]
+    until: Option<String>,
+
+    #[options(
+        help = "output format: human, json",
+        default = "human",
+        meta = "FORMAT"
+    )]
+    format: String,
+}
+
+#[derive(Options)]
+struct WaitHealthyOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "container name or ID")]
+    container: Option<String>,
+
+    #[options(help = "seconds to wait", default = "60", meta = "SECS")]
+    timeout: u64,
+}
+
+#[derive(Options)]
+struct DockerBuildOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "build context directory (default: .)")]
+    context: Option<String>,
+
+    #[options(help = "image name (repeatable)", meta = "NAME[:TAG]")]
+    tag: Vec<String>,
+
+    #[options(
+        short = "f",
+        help = "Dockerfile path (default: <context>/Dockerfile)",
+        meta = "PATH"
+    )]
+    file: Option<String>,
+
+    #[options(
+        no_short,
+        help = "build-time variable (repeatable)",
+        meta = "KEY[=VALUE]"
+    )]
+    build_arg: Vec<String>,
+
+    #[options(
+        no_short,
+        help = "stage of a multi-stage Dockerfile to build",
+        meta = "STAGE"
+    )]
+    target: Option<String>,
+
+    #[options(no_short, help = "do not use the build cache")]
+    no_cache: bool,
+
+    #[options(no_short, help = "always pull newer base images")]
+    pull: bool,
 }
 
 #[derive(Options)]
@@ -626,6 +1384,9 @@ enum SecurityCmd {
 
     #[options(help = "hash password (argon2/bcrypt)")]
     HashPassword(HashPasswordOpts),
+
+    #[options(help = "scan files for committed secrets")]
+    ScanSecrets(ScanSecretsOpts),
 }
 
 #[derive(Options)]
@@ -671,6 +1432,21 @@ struct HashPasswordOpts {
     algorithm: String,
 }
 
+#[derive(Options)]
+struct ScanSecretsOpts {
+    #[options(help = "show help")]
+    help: bool,
+
+    #[options(free, help = "file or directory to scan (default: .)")]
+    path: Option<String>,
+
+    #[options(help = "output format: text, json, sarif", meta = "FORMAT")]
+    format: Option<String>,
+
+    #[options(help = "ignore findings from a previous JSON report", meta = "FILE")]
+    baseline: Option<String>,
+}
+
 #[derive(Options)]
 struct PackageOpts {
     #[options(help = "show help for package")]
@@ -1587,7 +2363,7 @@ fn main() {
         Command::Git(opts) => {
             if opts.help {
                 println!("Usage: profilecore git <command>");
-                println!("Commands: status, log, diff, branch, remote, switch-account, add-account, list-accounts, whoami, clone, pull, push, stash, commit, tag, rebase");
+                println!("Commands: status, log, diff, branch, prune-branches, remote, switch-account, add-account, list-accounts, whoami, clone, pull, push, stash, commit, lint-commits, changelog, tag, rebase, worktree, undo, stats, blame, hooks, scan-secrets, large-files, submodule");
                 return;
             }
 
@@ -1602,7 +2378,19 @@ fn main() {
                     commands::git::diff();
                 }
                 Some(GitCmd::Branch(branch_opts)) => {
-                    commands::git::branch(branch_opts.all);
+                    commands::git::branch(
+                        branch_opts.all,
+                        branch_opts.merged,
+                        branch_opts.gone,
+                        branch_opts.stale,
+                    );
+                }
+                Some(GitCmd::PruneBranches(prune_opts)) => {
+                    commands::git::prune_branches(
+                        prune_opts.dry_run,
+                        prune_opts.yes,
+                        prune_opts.include_unmerged,
+                    );
                 }
                 Some(GitCmd::Remote(_)) => {
                     commands::git::remote();
@@ -1611,7 +2399,12 @@ fn main() {
                     commands::git::switch_account(&switch_opts.account);
                 }
                 Some(GitCmd::AddAccount(add_opts)) => {
-                    commands::git::add_account(add_opts.name, add_opts.email, add_opts.signing_key);
+                    commands::git::add_account(
+                        add_opts.name,
+                        add_opts.email,
+                        add_opts.signing_key,
+                        add_opts.url_pattern,
+                    );
                 }
                 Some(GitCmd::ListAccounts(_)) => {
                     commands::git::list_accounts();
@@ -1630,7 +2423,14 @@ fn main() {
                     } else {
                         None
                     };
-                    commands::git::clone(&clone_opts.args[0], path);
+                    commands::git::clone(
+                        &clone_opts.args[0],
+                        path,
+                        clone_opts.depth,
+                        clone_opts.branch.as_deref(),
+                        clone_opts.recurse_submodules,
+                        clone_opts.account.as_deref(),
+                    );
                 }
                 Some(GitCmd::Pull(_)) => {
                     commands::git::pull();
@@ -1640,23 +2440,145 @@ fn main() {
                 }
                 Some(GitCmd::Stash(stash_opts)) => {
                     let action = stash_opts.action.as_deref().unwrap_or("save");
-                    commands::git::stash(action);
+                    commands::git::stash(
+                        action,
+                        &stash_opts.args,
+                        stash_opts.message.as_deref(),
+                        stash_opts.include_untracked,
+                        stash_opts.keep_index,
+                    );
                 }
                 Some(GitCmd::Commit(commit_opts)) => {
-                    commands::git::commit(&commit_opts.message, commit_opts.all);
+                    let committed = commands::git::commit(
+                        &commit_opts.message,
+                        commit_opts.all,
+                        commit_opts.lint,
+                        commit_opts.no_lint,
+                        commit_opts.interactive,
+                    );
+                    if !committed {
+                        process::exit(1);
+                    }
+                }
+                Some(GitCmd::LintCommits(lint_opts)) => {
+                    if !commands::git::lint_commits(lint_opts.range.as_deref()) {
+                        process::exit(1);
+                    }
+                }
+                Some(GitCmd::Changelog(changelog_opts)) => {
+                    commands::git::changelog(
+                        changelog_opts.range.as_deref(),
+                        changelog_opts.format.as_deref(),
+                        changelog_opts.version.as_deref(),
+                        changelog_opts.prepend.as_deref(),
+                    );
                 }
                 Some(GitCmd::Tag(tag_opts)) => {
                     if tag_opts.list {
                         commands::git::tag("", None, true);
+                    } else if tag_opts.bump.is_some() || tag_opts.infer {
+                        commands::git::tag_bump(
+                            tag_opts.bump.as_deref().unwrap_or("auto"),
+                            tag_opts.infer,
+                            tag_opts.message.as_deref(),
+                            tag_opts.sign,
+                            tag_opts.dry_run,
+                            tag_opts.force,
+                        );
                     } else if let Some(name) = &tag_opts.name {
                         commands::git::tag(name, tag_opts.message.as_deref(), false);
                     } else {
-                        eprintln!("Error: tag name required (or use --list)");
+                        eprintln!("Error: tag name required (or use --list / --bump)");
                         process::exit(1);
                     }
                 }
                 Some(GitCmd::Rebase(rebase_opts)) => {
-                    commands::git::rebase(&rebase_opts.branch, rebase_opts.interactive);
+                    commands::git::rebase(
+                        &rebase_opts.branch,
+                        rebase_opts.interactive,
+                        rebase_opts.plan.as_deref(),
+                        rebase_opts.print_plan,
+                        rebase_opts.cont,
+                        rebase_opts.abort,
+                    );
+                }
+                Some(GitCmd::Undo(undo_opts)) => {
+                    commands::git::undo(
+                        undo_opts.limit,
+                        undo_opts.to,
+                        undo_opts.mode.as_deref(),
+                        undo_opts.yes,
+                    );
+                }
+                Some(GitCmd::Worktree(worktree_opts)) => {
+                    let action = worktree_opts.action.as_deref().unwrap_or("list");
+                    commands::git::worktree(
+                        action,
+                        &worktree_opts.args,
+                        worktree_opts.new_branch,
+                        worktree_opts.force,
+                    );
+                }
+                Some(GitCmd::Stats(stats_opts)) => {
+                    commands::git::stats(
+                        stats_opts.top,
+                        stats_opts.format.as_deref(),
+                        &stats_opts.period,
+                        stats_opts.depth,
+                        stats_opts.no_cache,
+                    );
+                }
+                Some(GitCmd::Blame(blame_opts)) => {
+                    if blame_opts.file.is_empty() {
+                        eprintln!("Error: file required");
+                        eprintln!("Usage: profilecore git blame <file> [-L START,END]");
+                        process::exit(1);
+                    }
+                    let format = if blame_opts.porcelain {
+                        Some("porcelain")
+                    } else {
+                        blame_opts.format.as_deref()
+                    };
+                    commands::git::blame(
+                        &blame_opts.file,
+                        blame_opts.lines.as_deref(),
+                        blame_opts.rev.as_deref(),
+                        blame_opts.ignore_whitespace,
+                        blame_opts.ignore_revs_file.as_deref(),
+                        blame_opts.no_ignore_revs,
+                        format,
+                    );
+                }
+                Some(GitCmd::Hooks(hooks_opts)) => {
+                    let action = hooks_opts.action.as_deref().unwrap_or("list");
+                    if !commands::git::hooks(action, &hooks_opts.args, hooks_opts.force) {
+                        process::exit(1);
+                    }
+                }
+                Some(GitCmd::ScanSecrets(scan_opts)) => {
+                    if !commands::git::scan_secrets(
+                        scan_opts.range.as_deref(),
+                        scan_opts.staged,
+                        scan_opts.format.as_deref(),
+                        scan_opts.baseline.as_deref(),
+                    ) {
+                        process::exit(1);
+                    }
+                }
+                Some(GitCmd::LargeFiles(large_opts)) => {
+                    commands::git::large_files(large_opts.top, &large_opts.min_size);
+                }
+                Some(GitCmd::Submodule(submodule_opts)) => {
+                    let action = submodule_opts.action.as_deref().unwrap_or("status");
+                    if !commands::git::submodule(
+                        action,
+                        &submodule_opts.args,
+                        submodule_opts.init,
+                        submodule_opts.recursive,
+                        submodule_opts.jobs,
+                    ) {
+                        process::exit(1);
+                    }
                 }
                 None => {
                     eprintln!("Error: No git command specified");
@@ -1667,20 +2589,226 @@ fn main() {
 
         Command::Docker(opts) => {
             if opts.help {
-                println!("Usage: profilecore docker <command>");
-                println!("Commands: ps, stats, logs");
+                println!("{}", DOCKER_HELP);
                 return;
             }
 
+            commands::docker::select_endpoint(opts.host, opts.context);
+
             match opts.command {
                 Some(DockerCmd::Ps(_)) => {
                     commands::docker::ps();
                 }
                 Some(DockerCmd::Stats(stats_opts)) => {
-                    commands::docker::stats(&stats_opts.container);
+                    if !commands::docker::stats(
+                        &stats_opts.containers,
+                        stats_opts.no_stream,
+                        &stats_opts.format,
+                        &stats_opts.sort,
+                    ) {
+                        process::exit(1);
+                    }
                 }
                 Some(DockerCmd::Logs(logs_opts)) => {
-                    commands::docker::logs(&logs_opts.container, logs_opts.lines);
+                    if logs_opts.containers.is_empty() {
+                        eprintln!("Error: No container specified");
+                        process::exit(1);
+                    }
+
+                    let options = commands::docker::LogOptions {
+                        tail: logs_opts.lines,
+                        follow: logs_opts.follow,
+                        since: logs_opts.since,
+                        until: logs_opts.until,
+                        timestamps: logs_opts.timestamps,
+                        grep: logs_opts.grep,
+                        highlight: logs_opts.highlight,
+                        stdout_only: logs_opts.stdout,
+                        stderr_only: logs_opts.stderr,
+                    };
+
+                    if !commands::docker::logs(&logs_opts.containers, &options) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Start(action_opts)) => docker_action("start", action_opts),
+                Some(DockerCmd::Stop(action_opts)) => docker_action("stop", action_opts),
+                Some(DockerCmd::Restart(action_opts)) => docker_action("restart", action_opts),
+                Some(DockerCmd::Rm(action_opts)) => docker_action("rm", action_opts),
+                Some(DockerCmd::Pause(action_opts)) => docker_action("pause", action_opts),
+                Some(DockerCmd::Unpause(action_opts)) => docker_action("unpause", action_opts),
+                Some(DockerCmd::Kill(action_opts)) => docker_action("kill", action_opts),
+                Some(DockerCmd::Images(images_opts)) => {
+                    if !commands::docker::images(images_opts.all, images_opts.dangling) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Volumes(_)) => {
+                    if !commands::docker::volumes() {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Networks(_)) => {
+                    if !commands::docker::networks() {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Inspect(inspect_opts)) => {
+                    let target = match &inspect_opts.target {
+                        Some(t) => t,
+                        None => {
+                            eprintln!("Error: No name or ID specified");
+                            process::exit(1);
+                        }
+                    };
+                    if !commands::docker::inspect(
+                        target,
+                        inspect_opts.kind.as_deref(),
+                        inspect_opts.json,
+                    ) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Df(_)) => {
+                    if !commands::docker::df() {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Prune(prune_opts)) => {
+                    // Volumes hold data, so they are only pruned when asked for
+                    let any = prune_opts.images
+                        || prune_opts.containers
+                        || prune_opts.volumes
+                        || prune_opts.build_cache;
+                    let options = commands::docker::PruneOptions {
+                        images: prune_opts.images || !any,
+                        containers: prune_opts.containers || !any,
+                        volumes: prune_opts.volumes || prune_opts.all_volumes,
+                        all_volumes: prune_opts.all_volumes,
+                        build_cache: prune_opts.build_cache || !any,
+                        older_than: prune_opts.older_than,
+                        dry_run: prune_opts.dry_run,
+                        yes: prune_opts.yes,
+                    };
+                    if !commands::docker::prune(&options) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Compose(compose_opts)) => {
+                    if compose_opts.help {
+                        println!("Usage: profilecore docker compose <command>");
+                        println!("Commands: ps, logs, restart");
+                        return;
+                    }
+
+                    let file = compose_opts.file.as_deref();
+                    let name = compose_opts.project_name.as_deref();
+                    let ok = match compose_opts.command {
+                        Some(ComposeCmd::Ps(_)) | None => commands::docker::compose_ps(file, name),
+                        Some(ComposeCmd::Logs(logs_opts)) => {
+                            let options = commands::docker::LogOptions {
+                                tail: logs_opts.lines,
+                                follow: logs_opts.follow,
+                                since: logs_opts.since,
+                                timestamps: logs_opts.timestamps,
+                                grep: logs_opts.grep,
+                                ..Default::default()
+                            };
+                            commands::docker::compose_logs(
+                                file,
+                                name,
+                                &logs_opts.services,
+                                &options,
+                            )
+                        }
+                        Some(ComposeCmd::Restart(restart_opts)) => {
+                            commands::docker::compose_restart(
+                                file,
+                                name,
+                                &restart_opts.services,
+                                restart_opts.time,
+                            )
+                        }
+                    };
+                    if !ok {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Events(events_opts)) => {
+                    let filters = commands::docker::EventFilters {
+                        types: events_opts.kind,
+                        containers: events_opts.container,
+                        images: events_opts.image,
+                        labels: events_opts.label,
+                        events: events_opts.event,
+                    };
+                    if !commands::docker::events(
+                        &filters,
+                        events_opts.since.as_deref(),
+                        events_opts.until.as_deref(),
+                        &events_opts.format,
+                    ) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Build(build_opts)) => {
+                    let options = commands::docker::BuildOptions {
+                        context: build_opts.context.unwrap_or_else(|| ".".to_string()),
+                        dockerfile: build_opts.file,
+                        tags: build_opts.tag,
+                        build_args: build_opts.build_arg,
+                        target: build_opts.target,
+                        no_cache: build_opts.no_cache,
+                        pull: build_opts.pull,
+                    };
+                    if !commands::docker::build(&options) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Context(_)) => {
+                    if !commands::docker::contexts() {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::WaitHealthy(wait_opts)) => {
+                    let container = match &wait_opts.container {
+                        Some(c) => c,
+                        None => {
+                            eprintln!("Error: No container specified");
+                            process::exit(1);
+                        }
+                    };
+                    if !commands::docker::wait_healthy(container, wait_opts.timeout) {
+                        process::exit(1);
+                    }
+                }
+                Some(DockerCmd::Exec(exec_opts)) => {
+                    let (container, command) = match exec_opts.args.split_first() {
+                        Some((container, command)) => (container, command.to_vec()),
+                        None => {
+                            eprintln!("Error: No container specified");
+                            eprintln!("Usage: profilecore docker exec [-it] <container> -- <cmd>");
+                            process::exit(1);
+                        }
+                    };
+                    let command = if command.is_empty() {
+                        vec!["sh".to_string()]
+                    } else {
+                        command
+                    };
+
+                    let options = commands::docker::ExecOptions {
+                        interactive: exec_opts.interactive,
+                        tty: exec_opts.tty,
+                        user: exec_opts.user,
+                        workdir: exec_opts.workdir,
+                        env: exec_opts.env,
+                    };
+
+                    let code = commands::docker::exec(container, &command, &options);
+                    if code != 0 {
+                        process::exit(code as i32);
+                    }
                 }
                 None => {
                     eprintln!("Error: No docker command specified");
@@ -1692,7 +2820,9 @@ fn main() {
         Command::Security(opts) => {
             if opts.help {
                 println!("Usage: profilecore security <command>");
-                println!("Commands: ssl-check, gen-password, check-password, hash-password");
+                println!(
+                    "Commands: ssl-check, gen-password, check-password, hash-password, scan-secrets"
+                );
                 return;
             }
 
@@ -1709,6 +2839,16 @@ fn main() {
                 Some(SecurityCmd::HashPassword(hash_opts)) => {
                     commands::security::hash_password(&hash_opts.password, &hash_opts.algorithm);
                 }
+                Some(SecurityCmd::ScanSecrets(scan_opts)) => {
+                    let path = scan_opts.path.as_deref().unwrap_or(".");
+                    if !commands::security::scan_secrets(
+                        path,
+                        scan_opts.format.as_deref(),
+                        scan_opts.baseline.as_deref(),
+                    ) {
+                        process::exit(1);
+                    }
+                }
                 None => {
                     eprintln!("Error: No security command specified");
                     process::exit(1);
@@ -2115,6 +3255,25 @@ fn main() {
     }
 }
 
+fn docker_action(action: &str, opts: ContainerActionOpts) {
+    let options = commands::docker::ActionOptions {
+        timeout: opts.time,
+        signal: opts.signal,
+        force: opts.force,
+        volumes: opts.volumes,
+    };
+
+    if !commands::docker::container_action(
+        action,
+        &opts.containers,
+        &opts.label,
+        &options,
+        opts.yes,
+    ) {
+        process::exit(1);
+    }
+}
+
 fn print_help() {
     println!("ProfileCore v1.0.0 - Unified Cross-Shell Interface");
     println!();
@@ -0,0 +1,351 @@
+//! Docker build context packing (`.dockerignore` rules, Dockerfile stages)
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One `.dockerignore` line
+#[derive(Debug)]
+struct IgnoreRule {
+    regex: Regex,
+    /// `!pattern`: re-include paths an earlier rule excluded
+    exception: bool,
+}
+
+/// `.dockerignore` rules, matched like the Docker CLI: the last matching rule wins
+/// and a rule that matches a directory also covers everything below it
+#[derive(Debug, Default)]
+pub struct DockerIgnore {
+    rules: Vec<IgnoreRule>,
+}
+
+impl DockerIgnore {
+    /// Rules from `<context>/.dockerignore`, or none if the file is missing
+    pub fn load(context: &Path) -> Result<Self> {
+        let path = context.join(".dockerignore");
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut rules = Vec::new();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (exception, pattern) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest.trim()),
+                None => (false, line),
+            };
+
+            let pattern = clean_pattern(pattern);
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let regex = Regex::new(&pattern_to_regex(&pattern))
+                .with_context(|| format!("invalid pattern '{}'", line))?;
+            rules.push(IgnoreRule { regex, exception });
+        }
+
+        Ok(Self { rules })
+    }
+
+    /// Whether a context-relative path (with `/` separators) is left out of the build
+    pub fn is_excluded(&self, path: &str) -> bool {
+        let mut excluded = false;
+
+        for rule in &self.rules {
+            // Only rules that would flip the current verdict need matching
+            if excluded != rule.exception {
+                continue;
+            }
+            let matched = rule.regex.is_match(path)
+                || parents(path).any(|parent| rule.regex.is_match(parent));
+            if matched {
+                excluded = !rule.exception;
+            }
+        }
+
+        excluded
+    }
+
+    /// Without `!` rules an excluded directory can be skipped without looking inside
+    fn has_exceptions(&self) -> bool {
+        self.rules.iter().any(|rule| rule.exception)
+    }
+}
+
+/// `a/b/c` yields `a` and `a/b`
+fn parents(path: &str) -> impl Iterator<Item = &str> {
+    path.match_indices('/').map(move |(i, _)| &path[..i])
+}
+
+/// Normalize like Go's `filepath.Clean`, dropping a leading `/`
+fn clean_pattern(pattern: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in pattern.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// Translate `*`, `?`, `**` and `[...]` into an anchored regex
+fn pattern_to_regex(pattern: &str) -> String {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut regex = String::from("^");
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                i += 1;
+                // `**/` also matches zero directories
+                if chars.get(i + 1) == Some(&'/') {
+                    i += 1;
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => match chars[i..].iter().position(|c| *c == ']') {
+                Some(end) => {
+                    let class: String = chars[i + 1..i + end].iter().collect();
+                    let class = match class.strip_prefix('^') {
+                        Some(rest) => format!("^{}", rest.replace('\\', "\\\\")),
+                        None => class.replace('\\', "\\\\"),
+                    };
+                    regex.push_str(&format!("[{}]", class));
+                    i += end;
+                }
+                None => regex.push_str("\\["),
+            },
+            '\\' if i + 1 < chars.len() => {
+                i += 1;
+                regex.push_str(&regex::escape(&chars[i].to_string()));
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+
+    regex.push('$');
+    regex
+}
+
+/// Name of the Dockerfile inside the context archive when it lives outside the context
+const EXTERNAL_DOCKERFILE: &str = ".dockerfile.profilecore";
+
+/// A packed build context
+pub struct BuildContext {
+    pub archive: Vec<u8>,
+    /// Path of the Dockerfile inside the archive
+    pub dockerfile: String,
+    pub files: usize,
+}
+
+/// Tar up `context` for the engine, honoring `.dockerignore`.
+///
+/// The Dockerfile (and `.dockerignore`) are always sent, as the Docker CLI does;
+/// `dockerfile_contents` replaces the file's contents in the archive.
+pub fn pack(context: &Path, dockerfile: &Path, dockerfile_contents: &str) -> Result<BuildContext> {
+    let ignore = DockerIgnore::load(context)?;
+
+    let dockerfile_name = match dockerfile.strip_prefix(context) {
+        Ok(relative) => to_slash(relative),
+        Err(_) => EXTERNAL_DOCKERFILE.to_string(),
+    };
+
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.follow_symlinks(false);
+
+    let mut files = 0;
+    let mut pending = vec![context.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries: Vec<PathBuf> = fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<_>>()?;
+        entries.sort();
+
+        for path in entries {
+            let relative = to_slash(path.strip_prefix(context).unwrap_or(&path));
+            if relative == dockerfile_name {
+                continue;
+            }
+
+            let is_dir = fs::symlink_metadata(&path)?.is_dir();
+            if ignore.is_excluded(&relative) && relative != ".dockerignore" {
+                // Exceptions may re-include something further down
+                if is_dir && ignore.has_exceptions() {
+                    pending.push(path);
+                }
+                continue;
+            }
+
+            builder
+                .append_path_with_name(&path, &relative)
+                .with_context(|| format!("Failed to add {}", path.display()))?;
+            if is_dir {
+                pending.push(path);
+            } else {
+                files += 1;
+            }
+        }
+    }
+
+    let mut header = tar::Header::new_gnu();
+    header.set_size(dockerfile_contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(
+        fs::metadata(dockerfile)
+            .ok()
+            .and_then(|m| m.modified().ok())
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    );
+    builder.append_data(
+        &mut header,
+        &dockerfile_name,
+        dockerfile_contents.as_bytes(),
+    )?;
+    files += 1;
+
+    Ok(BuildContext {
+        archive: builder.into_inner()?,
+        dockerfile: dockerfile_name,
+        files,
+    })
+}
+
+fn to_slash(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Cut a multi-stage Dockerfile after the `target` stage, so the classic builder's
+/// final image is that stage (stages can only depend on earlier ones)
+pub fn truncate_to_stage(dockerfile: &str, target: &str) -> Result<String> {
+    let from = Regex::new(r"(?i)^\s*FROM\s").unwrap();
+    let stage_name = Regex::new(r"(?i)\sAS\s+(\S+)\s*$").unwrap();
+
+    let mut output = String::new();
+    let mut in_target = false;
+    let mut continued = false;
+
+    for line in dockerfile.lines() {
+        // Continuation lines belong to the instruction above
+        let starts_instruction = !continued;
+        continued = line.trim_end().ends_with('\\');
+
+        if starts_instruction && from.is_match(line) {
+            if in_target {
+                return Ok(output);
+            }
+            let name = stage_name.captures(line).map(|c| c[1].to_string());
+            in_target = name.is_some_and(|n| n.eq_ignore_ascii_case(target));
+        }
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    if !in_target {
+        bail!("target stage '{}' not found in Dockerfile", target);
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_dockerignore_rules() {
+        let ignore = DockerIgnore::parse(
+            "# build output\n/target\n*.log\n**/node_modules\ndocs/*\n!docs/README.md\n",
+        )
+        .unwrap();
+
+        assert!(ignore.is_excluded("target"));
+        assert!(ignore.is_excluded("target/debug/app"));
+        assert!(ignore.is_excluded("error.log"));
+        assert!(!ignore.is_excluded("logs/error.log"));
+        assert!(ignore.is_excluded("web/app/node_modules/lib.js"));
+        assert!(ignore.is_excluded("node_modules"));
+        assert!(ignore.is_excluded("docs/guide.md"));
+        assert!(!ignore.is_excluded("docs/README.md"));
+        assert!(!ignore.is_excluded("src/main.rs"));
+    }
+
+    #[test]
+    fn test_pack_honors_dockerignore() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("target/debug")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("target/debug/app"), "binary").unwrap();
+        fs::write(root.join("Dockerfile"), "FROM scratch\n").unwrap();
+        fs::write(root.join(".dockerignore"), "target\nDockerfile\n").unwrap();
+
+        let context = pack(root, &root.join("Dockerfile"), "FROM scratch\n").unwrap();
+        let mut archive = tar::Archive::new(context.archive.as_slice());
+        let names: Vec<String> = archive
+            .entries()
+            .unwrap()
+            .map(|e| e.unwrap().path().unwrap().to_string_lossy().to_string())
+            .collect();
+
+        assert_eq!(context.dockerfile, "Dockerfile");
+        assert!(names.contains(&"src/main.rs".to_string()));
+        assert!(names.contains(&"Dockerfile".to_string()));
+        assert!(names.contains(&".dockerignore".to_string()));
+        assert!(!names.iter().any(|n| n.starts_with("target")));
+    }
+
+    #[test]
+    fn test_truncate_to_stage() {
+        let dockerfile = "\
+FROM rust:1 AS build
+RUN cargo build \\
+    --release
+FROM debian AS runtime
+COPY --from=build /app /app
+FROM runtime AS debug
+RUN apt-get install gdb
+";
+
+        let build = truncate_to_stage(dockerfile, "build").unwrap();
+        assert!(build.ends_with("--release\n"));
+        assert!(!build.contains("debian"));
+
+        let runtime = truncate_to_stage(dockerfile, "RUNTIME").unwrap();
+        assert!(runtime.contains("COPY --from=build"));
+        assert!(!runtime.contains("gdb"));
+
+        assert_eq!(truncate_to_stage(dockerfile, "debug").unwrap(), dockerfile);
+        assert!(truncate_to_stage(dockerfile, "missing").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+//! Docker Compose file discovery and parsing
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File names Compose looks for, in order of preference
+pub const COMPOSE_FILES: &[&str] = &[
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yaml",
+    "docker-compose.yml",
+];
+
+/// Labels Compose puts on every container it creates
+pub const PROJECT_LABEL: &str = "com.docker.compose.project";
+pub const SERVICE_LABEL: &str = "com.docker.compose.service";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ComposeFile {
+    /// Top-level project name (overrides the directory name)
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub services: BTreeMap<String, ComposeService>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ComposeService {
+    #[serde(default)]
+    pub image: Option<String>,
+
+    /// Build context, either a path or a mapping with `context`
+    #[serde(default)]
+    pub build: Option<serde_yaml::Value>,
+
+    /// Profiles that must be active for the service to start
+    #[serde(default)]
+    pub profiles: Vec<String>,
+
+    #[serde(default)]
+    pub deploy: Option<ComposeDeploy>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ComposeDeploy {
+    #[serde(default)]
+    pub replicas: Option<u32>,
+}
+
+impl ComposeService {
+    /// What the service runs: its image, or the build context
+    pub fn source(&self) -> String {
+        if let Some(image) = &self.image {
+            return image.clone();
+        }
+
+        match &self.build {
+            Some(serde_yaml::Value::String(context)) => format!("build: {}", context),
+            Some(build) => {
+                let context = build.get("context").and_then(|c| c.as_str()).unwrap_or(".");
+                format!("build: {}", context)
+            }
+            None => "-".to_string(),
+        }
+    }
+
+    /// Number of containers Compose starts for this service; none when
+    /// its profiles aren't enabled through COMPOSE_PROFILES
+    pub fn replicas(&self) -> u32 {
+        let active = std::env::var("COMPOSE_PROFILES").unwrap_or_default();
+        let enabled = self.profiles.is_empty()
+            || active
+                .split(',')
+                .any(|p| self.profiles.iter().any(|wanted| wanted == p.trim()));
+
+        if enabled {
+            self.deploy.as_ref().and_then(|d| d.replicas).unwrap_or(1)
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ComposeProject {
+    pub name: String,
+    pub path: PathBuf,
+    pub file: ComposeFile,
+}
+
+impl ComposeProject {
+    /// Load the project from `file`, or the first compose file found in `dir`.
+    /// The name comes from `name_override`, COMPOSE_PROJECT_NAME, the file's `name`
+    /// or the directory name, in that order.
+    pub fn load(dir: &Path, file: Option<&Path>, name_override: Option<&str>) -> Result<Self> {
+        let path = match file {
+            Some(f) => dir.join(f),
+            None => match COMPOSE_FILES
+                .iter()
+                .map(|f| dir.join(f))
+                .find(|p| p.exists())
+            {
+                Some(p) => p,
+                None => bail!(
+                    "No compose file found in {} (looked for {})",
+                    dir.display(),
+                    COMPOSE_FILES.join(", ")
+                ),
+            },
+        };
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file: ComposeFile = serde_yaml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        let project_dir = path.parent().unwrap_or(dir);
+        let dir_name = project_dir
+            .canonicalize()
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .unwrap_or_default();
+
+        let name = name_override
+            .map(String::from)
+            .or_else(|| std::env::var("COMPOSE_PROJECT_NAME").ok())
+            .or_else(|| file.name.clone())
+            .unwrap_or(dir_name);
+
+        Ok(Self {
+            name: normalize_project_name(&name),
+            path,
+            file,
+        })
+    }
+}
+
+/// Project names are lowercase letters, digits, dashes and underscores
+pub fn normalize_project_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_compose_project() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("docker-compose.yml"),
+            r#"
+services:
+  web:
+    build:
+      context: ./web
+    deploy:
+      replicas: 2
+  db:
+    image: postgres:16
+  worker:
+    build: .
+  debug:
+    image: busybox
+    profiles: [never-enabled-in-tests]
+"#,
+        )
+        .unwrap();
+
+        let project = ComposeProject::load(dir.path(), None, Some("My App")).unwrap();
+        assert_eq!(project.name, "myapp");
+        assert_eq!(project.file.services.len(), 4);
+        assert_eq!(project.file.services["db"].source(), "postgres:16");
+        assert_eq!(project.file.services["web"].source(), "build: ./web");
+        assert_eq!(project.file.services["worker"].source(), "build: .");
+        assert_eq!(project.file.services["web"].replicas(), 2);
+        assert_eq!(project.file.services["db"].replicas(), 1);
+        assert_eq!(project.file.services["debug"].replicas(), 0);
+    }
+
+    #[test]
+    fn test_load_prefers_compose_yaml_and_name() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("docker-compose.yml"), "services: {}").unwrap();
+        fs::write(
+            dir.path().join("compose.yaml"),
+            "name: shop\nservices:\n  api:\n    image: api\n",
+        )
+        .unwrap();
+
+        let project = ComposeProject::load(dir.path(), None, None).unwrap();
+        assert!(project.path.ends_with("compose.yaml"));
+        assert_eq!(project.file.services.len(), 1);
+
+        let empty = tempfile::tempdir().unwrap();
+        assert!(ComposeProject::load(empty.path(), None, None).is_err());
+    }
+}
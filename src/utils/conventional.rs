@@ -0,0 +1,278 @@
+//! Conventional Commits parsing and linting
+//!
+//! See https://www.conventionalcommits.org/en/v1.0.0/
+
+use crate::config::CommitSettings;
+use regex::Regex;
+
+/// A commit message parsed according to the Conventional Commits spec
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConventionalCommit {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub description: String,
+    pub body: Option<String>,
+    pub footers: Vec<(String, String)>,
+    pub breaking: bool,
+}
+
+impl ConventionalCommit {
+    /// Parse a full commit message, returning `None` if the header is not conventional
+    pub fn parse(message: &str) -> Option<Self> {
+        let header_re = Regex::new(
+            r"^(?P<type>[A-Za-z]+)(?:\((?P<scope>[^()]*)\))?(?P<bang>!)?: (?P<desc>.+)$",
+        )
+        .unwrap();
+
+        let mut lines = message.trim_end().lines();
+        let header = lines.next()?;
+        let caps = header_re.captures(header)?;
+
+        let rest: Vec<&str> = lines.collect();
+        let (body, footers) = split_body_and_footers(&rest);
+
+        let bang = caps.name("bang").is_some();
+        let breaking = bang || footers.iter().any(|(token, _)| is_breaking_token(token));
+
+        Some(Self {
+            commit_type: caps["type"].to_string(),
+            scope: caps.name("scope").map(|s| s.as_str().to_string()),
+            description: caps["desc"].to_string(),
+            body,
+            footers,
+            breaking,
+        })
+    }
+
+    /// Description of the breaking change, from the footer or the header
+    pub fn breaking_description(&self) -> Option<&str> {
+        if !self.breaking {
+            return None;
+        }
+
+        self.footers
+            .iter()
+            .find(|(token, _)| is_breaking_token(token))
+            .map(|(_, value)| value.as_str())
+            .or(Some(self.description.as_str()))
+    }
+}
+
+/// Build a commit message from its conventional parts
+pub fn format_message(
+    commit_type: &str,
+    scope: Option<&str>,
+    breaking: bool,
+    description: &str,
+    body: Option<&str>,
+    breaking_note: Option<&str>,
+) -> String {
+    let mut message = commit_type.to_string();
+    if let Some(scope) = scope.filter(|s| !s.is_empty()) {
+        message.push_str(&format!("({})", scope));
+    }
+    if breaking {
+        message.push('!');
+    }
+    message.push_str(": ");
+    message.push_str(description);
+
+    if let Some(body) = body.filter(|b| !b.is_empty()) {
+        message.push_str("\n\n");
+        message.push_str(body);
+    }
+
+    if let Some(note) = breaking_note.filter(|n| !n.is_empty()) {
+        message.push_str("\n\nBREAKING CHANGE: ");
+        message.push_str(note);
+    }
+
+    message
+}
+
+/// Check a commit message against the configured rules, returning all violations
+pub fn lint_message(message: &str, rules: &CommitSettings) -> Vec<String> {
+    let mut errors = Vec::new();
+    let header = message.lines().next().unwrap_or("");
+
+    if header.trim().is_empty() {
+        errors.push("commit message is empty".to_string());
+        return errors;
+    }
+
+    if header.chars().count() > rules.max_subject_length {
+        errors.push(format!(
+            "header is {} characters (max {})",
+            header.chars().count(),
+            rules.max_subject_length
+        ));
+    }
+
+    if let Some(second) = message.lines().nth(1) {
+        if !second.trim().is_empty() {
+            errors.push("header must be followed by a blank line".to_string());
+        }
+    }
+
+    let commit = match ConventionalCommit::parse(message) {
+        Some(c) => c,
+        None => {
+            errors.push("header must match 'type(scope): description'".to_string());
+            return errors;
+        }
+    };
+
+    if !rules.types.is_empty() && !rules.types.contains(&commit.commit_type) {
+        errors.push(format!(
+            "type '{}' is not allowed (expected one of: {})",
+            commit.commit_type,
+            rules.types.join(", ")
+        ));
+    }
+
+    match commit.scope.as_deref() {
+        None if rules.require_scope => errors.push("scope is required".to_string()),
+        Some("") => errors.push("scope must not be empty".to_string()),
+        Some(scope) if !rules.scopes.is_empty() && !rules.scopes.iter().any(|s| s == scope) => {
+            errors.push(format!(
+                "scope '{}' is not allowed (expected one of: {})",
+                scope,
+                rules.scopes.join(", ")
+            ));
+        }
+        _ => {}
+    }
+
+    if commit.description.trim().is_empty() {
+        errors.push("description must not be empty".to_string());
+    } else if commit.description.ends_with('.') {
+        errors.push("description must not end with a period".to_string());
+    }
+
+    let breaking_footer = commit
+        .footers
+        .iter()
+        .find(|(token, _)| is_breaking_token(token));
+
+    if let Some((_, value)) = breaking_footer {
+        if value.trim().is_empty() {
+            errors.push("BREAKING CHANGE footer must have a description".to_string());
+        }
+    } else if rules.require_breaking_footer && header.contains("!:") {
+        errors.push("breaking change '!' requires a 'BREAKING CHANGE:' footer".to_string());
+    }
+
+    errors
+}
+
+fn is_breaking_token(token: &str) -> bool {
+    token == "BREAKING CHANGE" || token == "BREAKING-CHANGE"
+}
+
+/// Split the lines after the header into a body and trailing footers
+fn split_body_and_footers(lines: &[&str]) -> (Option<String>, Vec<(String, String)>) {
+    let footer_re =
+        Regex::new(r"^(?P<token>BREAKING[ -]CHANGE|[\w-]+)(?:: | #)(?P<value>.*)$").unwrap();
+
+    // Footers live in the last paragraph
+    let text = lines.join("\n");
+    let text = text.trim();
+    if text.is_empty() {
+        return (None, Vec::new());
+    }
+
+    let (body, last) = match text.rfind("\n\n") {
+        Some(idx) => (Some(text[..idx].trim()), &text[idx + 2..]),
+        None => (None, text),
+    };
+
+    let starts_with_footer = last
+        .lines()
+        .next()
+        .is_some_and(|line| footer_re.is_match(line));
+
+    if !starts_with_footer {
+        return (Some(text.to_string()), Vec::new());
+    }
+
+    let mut footers: Vec<(String, String)> = Vec::new();
+    for line in last.lines() {
+        match footer_re.captures(line) {
+            Some(caps) => footers.push((caps["token"].to_string(), caps["value"].to_string())),
+            // Continuation lines belong to the previous footer
+            None => {
+                if let Some((_, value)) = footers.last_mut() {
+                    value.push('\n');
+                    value.push_str(line);
+                }
+            }
+        }
+    }
+
+    (body.map(|b| b.to_string()), footers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_header() {
+        let commit = ConventionalCommit::parse("feat(git): add stash list").unwrap();
+        assert_eq!(commit.commit_type, "feat");
+        assert_eq!(commit.scope.as_deref(), Some("git"));
+        assert_eq!(commit.description, "add stash list");
+        assert!(!commit.breaking);
+
+        assert!(ConventionalCommit::parse("Add stash list").is_none());
+    }
+
+    #[test]
+    fn test_parse_body_and_footers() {
+        let message = "fix!: drop v1 config\n\nOld files are ignored.\n\nBREAKING CHANGE: config moved\nRefs #42";
+        let commit = ConventionalCommit::parse(message).unwrap();
+        assert!(commit.breaking);
+        assert_eq!(commit.body.as_deref(), Some("Old files are ignored."));
+        assert_eq!(commit.footers.len(), 2);
+        assert_eq!(commit.breaking_description(), Some("config moved"));
+        assert_eq!(commit.footers[1], ("Refs".to_string(), "42".to_string()));
+    }
+
+    #[test]
+    fn test_lint_message() {
+        let rules = CommitSettings::default();
+        assert!(lint_message("feat: add thing", &rules).is_empty());
+        assert!(!lint_message("added thing", &rules).is_empty());
+        assert!(!lint_message("feature: add thing", &rules).is_empty());
+        assert!(!lint_message("fix: trailing period.", &rules).is_empty());
+        assert!(!lint_message("fix: ok\nno blank line", &rules).is_empty());
+
+        let strict = CommitSettings {
+            require_scope: true,
+            scopes: vec!["git".to_string()],
+            require_breaking_footer: true,
+            max_subject_length: 20,
+            ..CommitSettings::default()
+        };
+        assert!(lint_message("feat(git): short", &strict).is_empty());
+        assert_eq!(lint_message("feat: short", &strict).len(), 1);
+        assert_eq!(lint_message("feat(docker): short", &strict).len(), 1);
+        assert_eq!(lint_message("feat(git)!: short", &strict).len(), 1);
+        assert_eq!(
+            lint_message("feat(git): this header is too long", &strict).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_format_message() {
+        assert_eq!(
+            format_message("feat", Some("git"), false, "add x", None, None),
+            "feat(git): add x"
+        );
+        assert_eq!(
+            format_message("fix", None, true, "drop y", Some("Body"), Some("y removed")),
+            "fix!: drop y\n\nBody\n\nBREAKING CHANGE: y removed"
+        );
+    }
+}
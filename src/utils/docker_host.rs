@@ -0,0 +1,629 @@
+//! Docker engine endpoint resolution
+//!
+//! Follows the Docker CLI's order: `--host`, `--context`, `DOCKER_HOST`,
+//! `DOCKER_CONTEXT`, then `docker.toml`, the CLI's current context and finally
+//! the first engine socket found on this machine (rootful, rootless or Podman).
+
+use crate::config::EndpointSettings;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the implicit context that stands for "environment or default socket"
+pub const DEFAULT_CONTEXT: &str = "default";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Address {
+    /// Unix socket path
+    Unix(PathBuf),
+    /// Windows named pipe (e.g. `//./pipe/docker_engine`)
+    NamedPipe(String),
+    /// `host:port` reached over TCP
+    Tcp(String),
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Address::Unix(path) => write!(f, "unix://{}", path.display()),
+            Address::NamedPipe(pipe) => write!(f, "npipe://{}", pipe),
+            Address::Tcp(addr) => write!(f, "tcp://{}", addr),
+        }
+    }
+}
+
+/// Client certificate, key and CA for a TLS endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsFiles {
+    pub ca: PathBuf,
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+impl TlsFiles {
+    /// `ca.pem`, `cert.pem` and `key.pem` in `dir`, all of which must exist
+    pub fn in_dir(dir: &Path) -> Result<Self> {
+        let files = Self {
+            ca: dir.join("ca.pem"),
+            cert: dir.join("cert.pem"),
+            key: dir.join("key.pem"),
+        };
+
+        for path in [&files.ca, &files.cert, &files.key] {
+            if !path.exists() {
+                bail!("missing TLS file {}", path.display());
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+/// Where the endpoint came from, shown when a connection fails
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    HostFlag,
+    ContextFlag(String),
+    HostEnv,
+    ContextEnv(String),
+    Settings,
+    CurrentContext(String),
+    Detected(&'static str),
+    Default,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::HostFlag => write!(f, "--host"),
+            Source::ContextFlag(name) => write!(f, "--context {}", name),
+            Source::HostEnv => write!(f, "DOCKER_HOST"),
+            Source::ContextEnv(name) => write!(f, "DOCKER_CONTEXT={}", name),
+            Source::Settings => write!(f, "docker.toml"),
+            Source::CurrentContext(name) => write!(f, "context {}", name),
+            Source::Detected(kind) => write!(f, "{} socket", kind),
+            Source::Default => write!(f, "default"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Endpoint {
+    pub address: Address,
+    /// Set for TCP endpoints that use TLS
+    pub tls: Option<TlsFiles>,
+    pub source: Source,
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)?;
+        if self.tls.is_some() {
+            write!(f, " (TLS)")?;
+        }
+        write!(f, " from {}", self.source)
+    }
+}
+
+/// A context from the Docker CLI's context store
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockerContext {
+    pub name: String,
+    pub host: Option<String>,
+    pub description: Option<String>,
+    /// Directory holding the context's TLS material, when it has any
+    pub tls_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextMeta {
+    #[serde(rename = "Name")]
+    name: String,
+
+    #[serde(rename = "Metadata", default)]
+    metadata: ContextMetadata,
+
+    #[serde(rename = "Endpoints", default)]
+    endpoints: HashMap<String, ContextEndpoint>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ContextMetadata {
+    #[serde(rename = "Description", default)]
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextEndpoint {
+    #[serde(rename = "Host", default)]
+    host: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CliConfig {
+    #[serde(rename = "currentContext", default)]
+    current_context: Option<String>,
+}
+
+/// Everything resolution reads from the machine, so it can be faked in tests
+pub struct Lookup {
+    pub env: HashMap<String, String>,
+    /// The Docker CLI config directory (`DOCKER_CONFIG` or `~/.docker`)
+    pub docker_config: PathBuf,
+    /// Sockets to probe, in order of preference
+    pub sockets: Vec<(PathBuf, &'static str)>,
+}
+
+impl Lookup {
+    pub fn system() -> Self {
+        let env: HashMap<String, String> = std::env::vars().collect();
+        let home = dirs::home_dir().unwrap_or_default();
+
+        let docker_config = env
+            .get("DOCKER_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".docker"));
+
+        let sockets = if cfg!(unix) {
+            let mut sockets = vec![(PathBuf::from("/var/run/docker.sock"), "Docker")];
+            if let Some(runtime) = env.get("XDG_RUNTIME_DIR") {
+                let runtime = PathBuf::from(runtime);
+                sockets.push((runtime.join("docker.sock"), "rootless Docker"));
+                sockets.push((
+                    runtime.join("podman").join("podman.sock"),
+                    "rootless Podman",
+                ));
+            }
+            sockets.push((home.join(".docker/run/docker.sock"), "Docker Desktop"));
+            sockets.push((PathBuf::from("/run/podman/podman.sock"), "Podman"));
+            sockets
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            env,
+            docker_config,
+            sockets,
+        }
+    }
+
+    fn var(&self, name: &str) -> Option<&str> {
+        self.env
+            .get(name)
+            .map(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// TLS files for a TCP endpoint selected by address, following
+    /// `DOCKER_TLS_VERIFY` / `DOCKER_CERT_PATH` like the Docker CLI
+    fn env_tls(&self) -> Result<Option<TlsFiles>> {
+        match self.var("DOCKER_TLS_VERIFY") {
+            Some(verify) if verify != "0" => {
+                let dir = self
+                    .var("DOCKER_CERT_PATH")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| self.docker_config.clone());
+                TlsFiles::in_dir(&dir).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn current_context(&self) -> Option<String> {
+        let contents = fs::read_to_string(self.docker_config.join("config.json")).ok()?;
+        let config: CliConfig = serde_json::from_str(&contents).ok()?;
+        config.current_context.filter(|name| !name.is_empty())
+    }
+
+    fn contexts_dir(&self) -> PathBuf {
+        self.docker_config.join("contexts")
+    }
+}
+
+/// Resolve the endpoint from flags, environment, settings and the local machine
+pub fn resolve(
+    host: Option<&str>,
+    context: Option<&str>,
+    settings: &EndpointSettings,
+) -> Result<Endpoint> {
+    resolve_with(host, context, settings, &Lookup::system())
+}
+
+pub fn resolve_with(
+    host: Option<&str>,
+    context: Option<&str>,
+    settings: &EndpointSettings,
+    lookup: &Lookup,
+) -> Result<Endpoint> {
+    if let Some(host) = host {
+        return from_host(host, lookup.env_tls()?, Source::HostFlag);
+    }
+
+    // An explicit "default" context skips every configured context
+    let mut contexts_allowed = true;
+
+    if let Some(name) = context {
+        if name != DEFAULT_CONTEXT {
+            return from_context(name, lookup, Source::ContextFlag(name.to_string()));
+        }
+        contexts_allowed = false;
+    }
+
+    if let Some(host) = lookup.var("DOCKER_HOST") {
+        return from_host(host, lookup.env_tls()?, Source::HostEnv);
+    }
+
+    if contexts_allowed {
+        if let Some(name) = lookup.var("DOCKER_CONTEXT") {
+            if name != DEFAULT_CONTEXT {
+                return from_context(name, lookup, Source::ContextEnv(name.to_string()));
+            }
+            contexts_allowed = false;
+        }
+    }
+
+    if let Some(host) = &settings.host {
+        let tls = match &settings.tls_cert_path {
+            Some(dir) => Some(TlsFiles::in_dir(&expand_home(dir))?),
+            None => lookup.env_tls()?,
+        };
+        return from_host(host, tls, Source::Settings);
+    }
+
+    if contexts_allowed {
+        let name = settings
+            .context
+            .clone()
+            .or_else(|| lookup.current_context());
+        if let Some(name) = name.filter(|n| n != DEFAULT_CONTEXT) {
+            let source = match settings.context {
+                Some(_) => Source::Settings,
+                None => Source::CurrentContext(name.clone()),
+            };
+            return from_context(&name, lookup, source);
+        }
+    }
+
+    if let Some((path, kind)) = lookup.sockets.iter().find(|(path, _)| path.exists()) {
+        return Ok(Endpoint {
+            address: Address::Unix(path.clone()),
+            tls: None,
+            source: Source::Detected(kind),
+        });
+    }
+
+    let address = if cfg!(windows) {
+        Address::NamedPipe("//./pipe/docker_engine".to_string())
+    } else {
+        Address::Unix(PathBuf::from("/var/run/docker.sock"))
+    };
+    Ok(Endpoint {
+        address,
+        tls: None,
+        source: Source::Default,
+    })
+}
+
+fn from_host(host: &str, tls: Option<TlsFiles>, source: Source) -> Result<Endpoint> {
+    let address = parse_host(host)?;
+
+    // `https://` always means TLS, so it needs certificates from somewhere
+    if host.starts_with("https://") && tls.is_none() {
+        bail!(
+            "{} needs client certificates (set DOCKER_TLS_VERIFY=1 and DOCKER_CERT_PATH)",
+            host
+        );
+    }
+
+    let tls = match address {
+        Address::Tcp(_) => tls,
+        _ => None,
+    };
+
+    Ok(Endpoint {
+        address,
+        tls,
+        source,
+    })
+}
+
+fn from_context(name: &str, lookup: &Lookup, source: Source) -> Result<Endpoint> {
+    let context = find_context(&lookup.contexts_dir(), name)?
+        .with_context(|| format!("context '{}' not found", name))?;
+
+    let host = context
+        .host
+        .with_context(|| format!("context '{}' has no docker endpoint", name))?;
+    let address = parse_host(&host)?;
+
+    let tls = match (&address, &context.tls_dir) {
+        (Address::Tcp(_), Some(dir)) => Some(
+            TlsFiles::in_dir(dir).with_context(|| format!("context '{}': bad TLS data", name))?,
+        ),
+        _ => None,
+    };
+
+    Ok(Endpoint {
+        address,
+        tls,
+        source,
+    })
+}
+
+/// Parse a `DOCKER_HOST`-style address
+pub fn parse_host(host: &str) -> Result<Address> {
+    let (scheme, rest) = match host.split_once("://") {
+        Some((scheme, rest)) => (scheme, rest),
+        // Bare paths are sockets, anything else is host:port
+        None if host.starts_with('/') => ("unix", host),
+        None => ("tcp", host),
+    };
+
+    match scheme {
+        "unix" if !rest.is_empty() => Ok(Address::Unix(PathBuf::from(rest))),
+        "npipe" if !rest.is_empty() => Ok(Address::NamedPipe(rest.to_string())),
+        "tcp" | "http" | "https" => {
+            let addr = rest.trim_end_matches('/');
+            if addr.is_empty() {
+                bail!("missing address in {}", host);
+            }
+            // The engine's conventional ports: 2376 with TLS, 2375 without
+            if addr
+                .rsplit_once(':')
+                .is_some_and(|(_, port)| port.parse::<u16>().is_ok())
+            {
+                Ok(Address::Tcp(addr.to_string()))
+            } else {
+                let port = if scheme == "https" { 2376 } else { 2375 };
+                Ok(Address::Tcp(format!("{}:{}", addr, port)))
+            }
+        }
+        "ssh" => bail!("ssh endpoints are not supported; forward the socket or use tcp://"),
+        _ => bail!("unsupported Docker host: {}", host),
+    }
+}
+
+/// The context store keys each context by the SHA-256 of its name
+fn context_id(name: &str) -> String {
+    Sha256::digest(name.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn find_context(contexts_dir: &Path, name: &str) -> Result<Option<DockerContext>> {
+    let meta = contexts_dir
+        .join("meta")
+        .join(context_id(name))
+        .join("meta.json");
+
+    if !meta.exists() {
+        return Ok(None);
+    }
+
+    read_context(contexts_dir, &meta).map(Some)
+}
+
+fn read_context(contexts_dir: &Path, meta_path: &Path) -> Result<DockerContext> {
+    let contents = fs::read_to_string(meta_path)
+        .with_context(|| format!("Failed to read {}", meta_path.display()))?;
+    let meta: ContextMeta = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", meta_path.display()))?;
+
+    let tls_dir = contexts_dir
+        .join("tls")
+        .join(context_id(&meta.name))
+        .join("docker");
+
+    Ok(DockerContext {
+        host: meta.endpoints.get("docker").and_then(|e| e.host.clone()),
+        description: meta.metadata.description,
+        tls_dir: tls_dir.is_dir().then_some(tls_dir),
+        name: meta.name,
+    })
+}
+
+/// All contexts in the Docker CLI's store, sorted by name
+pub fn list_contexts(lookup: &Lookup) -> Result<Vec<DockerContext>> {
+    let contexts_dir = lookup.contexts_dir();
+    let meta_dir = contexts_dir.join("meta");
+
+    if !meta_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut contexts = Vec::new();
+    for entry in
+        fs::read_dir(&meta_dir).with_context(|| format!("Failed to read {}", meta_dir.display()))?
+    {
+        let meta = entry?.path().join("meta.json");
+        if meta.exists() {
+            contexts.push(read_context(&contexts_dir, &meta)?);
+        }
+    }
+
+    contexts.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(contexts)
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn lookup(dir: &TempDir, env: &[(&str, &str)]) -> Lookup {
+        Lookup {
+            env: env
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            docker_config: dir.path().to_path_buf(),
+            sockets: Vec::new(),
+        }
+    }
+
+    fn write_context(dir: &TempDir, name: &str, host: &str, tls: bool) {
+        let meta = dir.path().join("contexts/meta").join(context_id(name));
+        fs::create_dir_all(&meta).unwrap();
+        fs::write(
+            meta.join("meta.json"),
+            format!(
+                r#"{{"Name":"{}","Metadata":{{}},"Endpoints":{{"docker":{{"Host":"{}","SkipTLSVerify":false}}}}}}"#,
+                name, host
+            ),
+        )
+        .unwrap();
+
+        if tls {
+            let certs = dir
+                .path()
+                .join("contexts/tls")
+                .join(context_id(name))
+                .join("docker");
+            write_certs(&certs);
+        }
+    }
+
+    fn write_certs(dir: &Path) {
+        fs::create_dir_all(dir).unwrap();
+        for file in ["ca.pem", "cert.pem", "key.pem"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_parse_host() {
+        assert_eq!(
+            parse_host("unix:///run/user/1000/podman/podman.sock").unwrap(),
+            Address::Unix(PathBuf::from("/run/user/1000/podman/podman.sock"))
+        );
+        assert_eq!(
+            parse_host("tcp://10.0.0.5:2376").unwrap(),
+            Address::Tcp("10.0.0.5:2376".to_string())
+        );
+        assert_eq!(
+            parse_host("https://build.local").unwrap(),
+            Address::Tcp("build.local:2376".to_string())
+        );
+        assert_eq!(
+            parse_host("/var/run/docker.sock").unwrap(),
+            Address::Unix(PathBuf::from("/var/run/docker.sock"))
+        );
+        assert!(parse_host("ssh://user@host").is_err());
+        assert!(parse_host("tcp://").is_err());
+    }
+
+    #[test]
+    fn test_resolve_precedence() {
+        let dir = TempDir::new().unwrap();
+        write_context(&dir, "remote", "tcp://remote:2375", false);
+        fs::write(
+            dir.path().join("config.json"),
+            r#"{"currentContext":"remote"}"#,
+        )
+        .unwrap();
+        let settings = EndpointSettings::default();
+
+        let env = lookup(&dir, &[("DOCKER_HOST", "unix:///tmp/env.sock")]);
+        let endpoint = resolve_with(Some("tcp://flag:2375"), None, &settings, &env).unwrap();
+        assert_eq!(endpoint.address, Address::Tcp("flag:2375".to_string()));
+        assert_eq!(endpoint.source, Source::HostFlag);
+
+        let endpoint = resolve_with(None, None, &settings, &env).unwrap();
+        assert_eq!(endpoint.source, Source::HostEnv);
+
+        let endpoint = resolve_with(None, Some("remote"), &settings, &env).unwrap();
+        assert_eq!(endpoint.address, Address::Tcp("remote:2375".to_string()));
+
+        // Without overrides the CLI's current context wins over socket detection
+        let plain = lookup(&dir, &[]);
+        let endpoint = resolve_with(None, None, &settings, &plain).unwrap();
+        assert_eq!(
+            endpoint.source,
+            Source::CurrentContext("remote".to_string())
+        );
+
+        let endpoint = resolve_with(None, Some(DEFAULT_CONTEXT), &settings, &plain).unwrap();
+        assert_ne!(endpoint.address, Address::Tcp("remote:2375".to_string()));
+
+        assert!(resolve_with(None, Some("missing"), &settings, &plain).is_err());
+    }
+
+    #[test]
+    fn test_resolve_tls() {
+        let dir = TempDir::new().unwrap();
+        write_context(&dir, "secure", "tcp://secure:2376", true);
+        let settings = EndpointSettings::default();
+        let plain = lookup(&dir, &[]);
+
+        let endpoint = resolve_with(None, Some("secure"), &settings, &plain).unwrap();
+        let tls = endpoint.tls.unwrap();
+        assert!(tls.cert.ends_with("docker/cert.pem"));
+
+        let certs = dir.path().join("certs");
+        write_certs(&certs);
+        let env = lookup(
+            &dir,
+            &[
+                ("DOCKER_HOST", "tcp://secure:2376"),
+                ("DOCKER_TLS_VERIFY", "1"),
+                ("DOCKER_CERT_PATH", certs.to_str().unwrap()),
+            ],
+        );
+        let endpoint = resolve_with(None, None, &settings, &env).unwrap();
+        assert_eq!(endpoint.tls.unwrap().ca, certs.join("ca.pem"));
+
+        // Verification requested but certificates missing
+        let broken = lookup(
+            &dir,
+            &[
+                ("DOCKER_HOST", "tcp://secure:2376"),
+                ("DOCKER_TLS_VERIFY", "1"),
+                ("DOCKER_CERT_PATH", "/nonexistent"),
+            ],
+        );
+        assert!(resolve_with(None, None, &settings, &broken).is_err());
+    }
+
+    #[test]
+    fn test_detect_socket() {
+        let dir = TempDir::new().unwrap();
+        let podman = dir.path().join("podman.sock");
+        fs::write(&podman, "").unwrap();
+
+        let mut plain = lookup(&dir, &[]);
+        plain.sockets = vec![
+            (dir.path().join("docker.sock"), "Docker"),
+            (podman.clone(), "rootless Podman"),
+        ];
+
+        let endpoint = resolve_with(None, None, &EndpointSettings::default(), &plain).unwrap();
+        assert_eq!(endpoint.address, Address::Unix(podman));
+        assert_eq!(endpoint.source, Source::Detected("rootless Podman"));
+    }
+
+    #[test]
+    fn test_list_contexts() {
+        let dir = TempDir::new().unwrap();
+        write_context(&dir, "zeta", "unix:///tmp/z.sock", false);
+        write_context(&dir, "alpha", "tcp://a:2376", true);
+
+        let contexts = list_contexts(&lookup(&dir, &[])).unwrap();
+        let names: Vec<&str> = contexts.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "zeta"]);
+        assert!(contexts[0].tls_dir.is_some());
+        assert!(contexts[1].tls_dir.is_none());
+    }
+}
@@ -2,6 +2,12 @@
 //!
 //! Provides common functionality used across commands
 
+pub mod build_context;
+pub mod compose;
+pub mod conventional;
+pub mod docker_host;
 pub mod fs_helpers;
 pub mod paths;
+pub mod secrets;
 pub mod shell;
+pub mod time;
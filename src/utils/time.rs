@@ -0,0 +1,24 @@
+//! Time formatting helpers
+
+/// Format a unix timestamp relative to now (e.g. "3 days ago")
+pub fn relative_time(timestamp: i64) -> String {
+    let delta = chrono::Utc::now().timestamp() - timestamp;
+    if delta < 0 {
+        return "in the future".to_string();
+    }
+
+    let (value, unit) = match delta {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => (delta / 60, "minute"),
+        3_600..=86_399 => (delta / 3_600, "hour"),
+        86_400..=2_591_999 => (delta / 86_400, "day"),
+        2_592_000..=31_535_999 => (delta / 2_592_000, "month"),
+        _ => (delta / 31_536_000, "year"),
+    };
+
+    if value == 1 {
+        format!("1 {} ago", unit)
+    } else {
+        format!("{} {}s ago", value, unit)
+    }
+}